@@ -0,0 +1,21 @@
+-- Emails are unique regardless of case. The index cannot be built while
+-- case-variant duplicates exist, so name all of them up front instead of
+-- failing on the first pair; merge or rename those accounts, then rerun.
+DO $$
+DECLARE
+    duplicates TEXT;
+BEGIN
+    SELECT string_agg(format('%s (ids %s)', address, ids), ', ')
+    INTO duplicates
+    FROM (SELECT lower(email) AS address, string_agg(id::TEXT, ', ' ORDER BY id) AS ids
+          FROM users
+          GROUP BY lower(email)
+          HAVING count(*) > 1) AS conflicts;
+    IF duplicates IS NOT NULL THEN
+        RAISE EXCEPTION 'users has emails that differ only in case: %', duplicates
+            USING HINT = 'Merge or rename these accounts before migrating.';
+    END IF;
+END
+$$;
+
+CREATE UNIQUE INDEX users_email_lower_idx ON users (lower(email));
@@ -0,0 +1,17 @@
+CREATE TABLE roles
+(
+    name VARCHAR(32) PRIMARY KEY
+);
+
+INSERT INTO roles (name) VALUES ('admin'), ('user');
+
+CREATE TABLE user_roles
+(
+    user_id INTEGER     NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    role    VARCHAR(32) NOT NULL REFERENCES roles (name),
+    PRIMARY KEY (user_id, role)
+);
+
+-- Existing accounts become regular users; admins are granted explicitly.
+INSERT INTO user_roles (user_id, role)
+SELECT id, 'user' FROM users;
@@ -0,0 +1,2 @@
+-- NULL for accounts created without a password; they cannot log in until one is set.
+ALTER TABLE users ADD COLUMN password_hash VARCHAR(255);
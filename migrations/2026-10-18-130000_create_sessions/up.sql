@@ -0,0 +1,14 @@
+CREATE TABLE sessions
+(
+    id                 UUID PRIMARY KEY,
+    user_id            INTEGER      NOT NULL REFERENCES users (id) ON DELETE CASCADE,
+    refresh_token_hash VARCHAR(64)  NOT NULL,
+    user_agent         VARCHAR(255),
+    ip_address         VARCHAR(45),
+    created_at         TIMESTAMPTZ  NOT NULL,
+    last_used_at       TIMESTAMPTZ  NOT NULL,
+    expires_at         TIMESTAMPTZ  NOT NULL,
+    revoked_at         TIMESTAMPTZ
+);
+
+CREATE INDEX sessions_user_id_idx ON sessions (user_id);
@@ -0,0 +1,3 @@
+pub struct BootstrapAdminInputDto {
+    pub email: String,
+}
@@ -0,0 +1,5 @@
+pub struct ChangePasswordInputDto {
+    pub id: i32,
+    pub current_password: String,
+    pub new_password: String,
+}
@@ -1,4 +1,5 @@
 pub struct CreateUserInputDto {
     pub name: String,
     pub email: String,
+    pub password: String,
 }
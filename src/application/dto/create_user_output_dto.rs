@@ -1,5 +1,8 @@
+use chrono::{DateTime, Utc};
+
 pub struct CreateUserOutputDto {
     pub id: i32,
     pub name: String,
     pub email: String,
+    pub email_verified_at: Option<DateTime<Utc>>,
 }
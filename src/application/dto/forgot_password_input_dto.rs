@@ -0,0 +1,3 @@
+pub struct ForgotPasswordInputDto {
+    pub email: String,
+}
@@ -0,0 +1,6 @@
+pub struct LoginInputDto {
+    pub email: String,
+    pub password: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
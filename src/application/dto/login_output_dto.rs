@@ -0,0 +1,5 @@
+pub struct LoginOutputDto {
+    pub access_token: String,
+    pub expires_in: u64,
+    pub refresh_token: String,
+}
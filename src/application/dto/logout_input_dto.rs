@@ -0,0 +1,3 @@
+pub struct LogoutInputDto {
+    pub refresh_token: String,
+}
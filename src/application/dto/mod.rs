@@ -1,8 +1,28 @@
+pub mod bootstrap_admin_input_dto;
+pub mod change_password_input_dto;
 pub mod create_user_input_dto;
 pub mod create_user_output_dto;
 pub mod delete_user_input_dto;
 pub mod delete_user_output_dto;
+pub mod forgot_password_input_dto;
+pub mod login_input_dto;
+pub mod login_output_dto;
+pub mod logout_input_dto;
+pub mod patch_user_input_dto;
+pub mod read_sessions_input_dto;
+pub mod read_sessions_output_dto;
 pub mod read_user_input_dto;
 pub mod read_user_output_dto;
+pub mod read_users_input_dto;
+pub mod read_users_output_dto;
+pub mod refresh_session_input_dto;
+pub mod refresh_session_output_dto;
+pub mod request_email_verification_input_dto;
+pub mod reset_password_input_dto;
+pub mod revoke_sessions_input_dto;
+pub mod search_users_input_dto;
+pub mod search_users_output_dto;
 pub mod update_user_input_dto;
 pub mod update_user_output_dto;
+pub mod verify_email_input_dto;
+pub mod verify_email_output_dto;
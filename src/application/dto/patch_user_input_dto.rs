@@ -0,0 +1,5 @@
+pub struct PatchUserInputDto {
+    pub id: i32,
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
@@ -0,0 +1,3 @@
+pub struct ReadSessionsInputDto {
+    pub user_id: i32,
+}
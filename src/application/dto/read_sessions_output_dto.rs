@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+pub struct ReadSessionOutputDto {
+    pub id: Uuid,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+pub struct ReadSessionsOutputDto {
+    pub sessions: Vec<ReadSessionOutputDto>,
+}
@@ -0,0 +1,10 @@
+pub struct ReadUsersInputDto {
+    pub limit: Option<i64>,
+    pub cursor: Option<i32>,
+    pub page: Option<i64>,
+    pub per_page: Option<i64>,
+    pub sort: Option<String>,
+    pub direction: Option<String>,
+    pub email_domain: Option<String>,
+    pub name_contains: Option<String>,
+}
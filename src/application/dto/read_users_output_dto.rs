@@ -0,0 +1,7 @@
+use crate::application::dto::read_user_output_dto::ReadUserOutputDto;
+
+pub struct ReadUsersOutputDto {
+    pub users: Vec<ReadUserOutputDto>,
+    pub total: i64,
+    pub next_cursor: Option<i32>,
+}
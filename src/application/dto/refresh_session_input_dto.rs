@@ -0,0 +1,3 @@
+pub struct RefreshSessionInputDto {
+    pub refresh_token: String,
+}
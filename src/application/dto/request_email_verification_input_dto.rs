@@ -0,0 +1,3 @@
+pub struct RequestEmailVerificationInputDto {
+    pub user_id: i32,
+}
@@ -0,0 +1,4 @@
+pub struct ResetPasswordInputDto {
+    pub token: String,
+    pub new_password: String,
+}
@@ -0,0 +1,7 @@
+use uuid::Uuid;
+
+pub struct RevokeSessionsInputDto {
+    pub user_id: i32,
+    /// `None` revokes every session of the user.
+    pub session_id: Option<Uuid>,
+}
@@ -0,0 +1,4 @@
+pub struct SearchUsersInputDto {
+    pub query: String,
+    pub limit: Option<i64>,
+}
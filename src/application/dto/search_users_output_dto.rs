@@ -0,0 +1,9 @@
+use chrono::{DateTime, Utc};
+
+pub struct SearchUsersOutputDto {
+    pub id: i32,
+    pub name: String,
+    pub email: String,
+    pub email_verified_at: Option<DateTime<Utc>>,
+    pub score: f32,
+}
@@ -0,0 +1,3 @@
+pub struct VerifyEmailInputDto {
+    pub token: String,
+}
@@ -0,0 +1,7 @@
+use chrono::{DateTime, Utc};
+
+pub struct VerifyEmailOutputDto {
+    pub id: i32,
+    pub email: String,
+    pub email_verified_at: Option<DateTime<Utc>>,
+}
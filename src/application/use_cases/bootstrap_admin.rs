@@ -0,0 +1,21 @@
+use crate::application::dto::bootstrap_admin_input_dto::BootstrapAdminInputDto;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::user_service::UserService;
+use tracing::instrument;
+
+pub struct BootstrapAdminUseCase {
+    service: Box<dyn UserService>,
+}
+
+impl BootstrapAdminUseCase {
+    pub fn new(service: Box<dyn UserService>) -> Self {
+        BootstrapAdminUseCase { service }
+    }
+
+    /// Runs at startup on behalf of the operator, so there is no caller to authorize.
+    #[instrument(name = "bootstrap_admin", skip_all, err(Display))]
+    pub async fn execute(&self, dto: BootstrapAdminInputDto) -> Result<(), UserError> {
+        self.service.ensure_admin(&dto.email).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::application::dto::change_password_input_dto::ChangePasswordInputDto;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::policies::user_policy::{authorize, UserAction};
+use crate::core::services::user_service::UserService;
+use tracing::instrument;
+
+pub struct ChangePasswordUseCase {
+    service: Box<dyn UserService>,
+}
+
+impl ChangePasswordUseCase {
+    pub fn new(service: Box<dyn UserService>) -> Self {
+        ChangePasswordUseCase { service }
+    }
+
+    #[instrument(name = "change_password", skip_all, fields(user_id = dto.id), err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        caller: &Caller,
+        dto: ChangePasswordInputDto,
+    ) -> Result<(), UserError> {
+        authorize(caller, UserAction::ChangePassword(dto.id))?;
+        self.service
+            .change_password(dto.id, &dto.current_password, &dto.new_password)
+            .await
+    }
+}
@@ -1,6 +1,10 @@
 use crate::application::dto::create_user_input_dto::CreateUserInputDto;
 use crate::application::dto::create_user_output_dto::CreateUserOutputDto;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::policies::user_policy::{authorize, UserAction};
 use crate::core::services::user_service::UserService;
+use tracing::instrument;
 
 pub struct CreateUserUseCase {
     service: Box<dyn UserService>,
@@ -11,14 +15,21 @@ impl CreateUserUseCase {
         CreateUserUseCase { service }
     }
 
-    pub fn execute(&self, dto: CreateUserInputDto) -> Result<CreateUserOutputDto, String> {
-        let last_id = self.service.get_last_user().map_or(0, |user| user.id + 1);
+    #[instrument(name = "create_user", skip_all, err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        caller: &Caller,
+        dto: CreateUserInputDto,
+    ) -> Result<CreateUserOutputDto, UserError> {
+        authorize(caller, UserAction::Create)?;
         self.service
-            .create_user(last_id, dto.name, dto.email)
+            .create_user(dto.name, dto.email, dto.password)
+            .await
             .map(|user| CreateUserOutputDto {
                 id: user.id,
                 name: user.name,
                 email: user.email,
+                email_verified_at: user.email_verified_at,
             })
     }
 }
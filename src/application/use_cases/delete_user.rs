@@ -1,6 +1,10 @@
 use crate::application::dto::delete_user_input_dto::DeleteUserInputDto;
 use crate::application::dto::delete_user_output_dto::DeleteUserOutputDto;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::policies::user_policy::{authorize, UserAction};
 use crate::core::services::user_service::UserService;
+use tracing::instrument;
 
 pub struct RemoveUserUseCase {
     service: Box<dyn UserService>,
@@ -11,14 +15,22 @@ impl RemoveUserUseCase {
         RemoveUserUseCase { service }
     }
 
-    pub fn execute(&self, dto: DeleteUserInputDto) -> Result<DeleteUserOutputDto, String> {
+    #[instrument(name = "delete_user", skip_all, fields(user_id = dto.id), err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        caller: &Caller,
+        dto: DeleteUserInputDto,
+    ) -> Result<DeleteUserOutputDto, UserError> {
+        authorize(caller, UserAction::Delete(dto.id))?;
         let id = dto.id;
         self.service
             .remove_user(id)
+            .await
             .map(|user| DeleteUserOutputDto {
                 id: user.id,
                 name: user.name,
                 email: user.email,
+                email_verified_at: user.email_verified_at,
             })
     }
 }
@@ -0,0 +1,20 @@
+use crate::application::dto::forgot_password_input_dto::ForgotPasswordInputDto;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::password_reset_service::PasswordResetService;
+use tracing::instrument;
+
+pub struct ForgotPasswordUseCase {
+    service: Box<dyn PasswordResetService>,
+}
+
+impl ForgotPasswordUseCase {
+    pub fn new(service: Box<dyn PasswordResetService>) -> Self {
+        ForgotPasswordUseCase { service }
+    }
+
+    /// Runs after the response is sent, so its errors are only ever logged.
+    #[instrument(name = "forgot_password", skip_all, err(Display))]
+    pub async fn execute(&self, dto: ForgotPasswordInputDto) -> Result<(), UserError> {
+        self.service.request_reset(&dto.email).await
+    }
+}
@@ -0,0 +1,42 @@
+use crate::application::dto::login_input_dto::LoginInputDto;
+use crate::application::dto::login_output_dto::LoginOutputDto;
+use crate::core::domain::session::SessionClient;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::session_service::SessionService;
+use crate::core::services::token_issuer::TokenIssuer;
+use crate::core::services::user_service::UserService;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub struct LoginUseCase {
+    service: Box<dyn UserService>,
+    sessions: Box<dyn SessionService>,
+    issuer: Arc<dyn TokenIssuer>,
+}
+
+impl LoginUseCase {
+    pub fn new(
+        service: Box<dyn UserService>,
+        sessions: Box<dyn SessionService>,
+        issuer: Arc<dyn TokenIssuer>,
+    ) -> Self {
+        LoginUseCase {
+            service,
+            sessions,
+            issuer,
+        }
+    }
+
+    #[instrument(name = "login", skip_all, err(Display, level = "info"))]
+    pub async fn execute(&self, dto: LoginInputDto) -> Result<LoginOutputDto, UserError> {
+        let user = self.service.authenticate(&dto.email, &dto.password).await?;
+        let token = self.issuer.issue(&user)?;
+        let client = SessionClient::new(dto.user_agent.as_deref(), dto.ip_address);
+        let grant = self.sessions.open_session(user.id, client).await?;
+        Ok(LoginOutputDto {
+            access_token: token.token,
+            expires_in: token.expires_in,
+            refresh_token: grant.refresh_token,
+        })
+    }
+}
@@ -0,0 +1,19 @@
+use crate::application::dto::logout_input_dto::LogoutInputDto;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::session_service::SessionService;
+use tracing::instrument;
+
+pub struct LogoutUseCase {
+    sessions: Box<dyn SessionService>,
+}
+
+impl LogoutUseCase {
+    pub fn new(sessions: Box<dyn SessionService>) -> Self {
+        LogoutUseCase { sessions }
+    }
+
+    #[instrument(name = "logout", skip_all, err(Display, level = "info"))]
+    pub async fn execute(&self, dto: LogoutInputDto) -> Result<(), UserError> {
+        self.sessions.close_session(&dto.refresh_token).await
+    }
+}
@@ -1,5 +1,18 @@
+pub mod bootstrap_admin;
+pub mod change_password;
 pub mod create_user;
 pub mod delete_user;
+pub mod forgot_password;
+pub mod login;
+pub mod logout;
+pub mod patch_user;
 pub mod read_all_users;
+pub mod read_sessions;
 pub mod read_user;
+pub mod refresh_session;
+pub mod request_email_verification;
+pub mod reset_password;
+pub mod revoke_sessions;
+pub mod search_users;
 pub mod update_user;
+pub mod verify_email;
@@ -0,0 +1,35 @@
+use crate::application::dto::patch_user_input_dto::PatchUserInputDto;
+use crate::application::dto::update_user_output_dto::UpdateUserOutputDto;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::policies::user_policy::{authorize, UserAction};
+use crate::core::services::user_service::UserService;
+use tracing::instrument;
+
+pub struct PatchUserUseCase {
+    service: Box<dyn UserService>,
+}
+
+impl PatchUserUseCase {
+    pub fn new(service: Box<dyn UserService>) -> Self {
+        PatchUserUseCase { service }
+    }
+
+    #[instrument(name = "patch_user", skip_all, fields(user_id = dto.id), err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        caller: &Caller,
+        dto: PatchUserInputDto,
+    ) -> Result<UpdateUserOutputDto, UserError> {
+        authorize(caller, UserAction::Update(dto.id))?;
+        self.service
+            .patch_user(dto.id, dto.name, dto.email)
+            .await
+            .map(|user| UpdateUserOutputDto {
+                id: user.id,
+                name: user.name,
+                email: user.email,
+                email_verified_at: user.email_verified_at,
+            })
+    }
+}
@@ -1,5 +1,14 @@
 use crate::application::dto::read_user_output_dto::ReadUserOutputDto;
+use crate::application::dto::read_users_input_dto::ReadUsersInputDto;
+use crate::application::dto::read_users_output_dto::ReadUsersOutputDto;
+use crate::core::domain::caller::Caller;
+use crate::core::domain::user_query::{
+    SortDirection, UserPagination, UserQuery, UserSortField, DEFAULT_PAGE_SIZE,
+};
+use crate::core::errors::user_error::{FieldError, UserError};
+use crate::core::policies::user_policy::{authorize, UserAction};
 use crate::core::services::user_service::UserService;
+use tracing::instrument;
 
 pub struct GetAllUsersUseCase {
     service: Box<dyn UserService>,
@@ -10,15 +19,78 @@ impl GetAllUsersUseCase {
         GetAllUsersUseCase { service }
     }
 
-    pub fn execute(&self) -> Vec<ReadUserOutputDto> {
-        self.service
-            .list_all_users()
-            .iter()
-            .map(|user| ReadUserOutputDto {
-                id: user.id,
-                name: user.name.clone(),
-                email: user.email.clone(),
-            })
-            .collect()
+    #[instrument(name = "read_all_users", skip_all, err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        caller: &Caller,
+        dto: ReadUsersInputDto,
+    ) -> Result<ReadUsersOutputDto, UserError> {
+        authorize(caller, UserAction::List)?;
+        let query = Self::build_query(dto)?;
+        let page = self.service.list_all_users(&query).await?;
+        Ok(ReadUsersOutputDto {
+            users: page
+                .users
+                .iter()
+                .map(|user| ReadUserOutputDto {
+                    id: user.id,
+                    name: user.name.clone(),
+                    email: user.email.clone(),
+                    email_verified_at: user.email_verified_at,
+                })
+                .collect(),
+            total: page.total,
+            next_cursor: page.next_cursor,
+        })
+    }
+
+    fn build_query(dto: ReadUsersInputDto) -> Result<UserQuery, UserError> {
+        let mut errors = Vec::new();
+
+        let sort = match dto.sort.as_deref() {
+            None => UserSortField::default(),
+            Some(value) => UserSortField::parse(value).unwrap_or_else(|| {
+                errors.push(FieldError::new("sort", "Must be one of id, name, email"));
+                UserSortField::default()
+            }),
+        };
+        let direction = match dto.direction.as_deref() {
+            None => SortDirection::default(),
+            Some(value) => SortDirection::parse(value).unwrap_or_else(|| {
+                errors.push(FieldError::new("direction", "Must be asc or desc"));
+                SortDirection::default()
+            }),
+        };
+
+        let paged = dto.page.is_some() || dto.per_page.is_some();
+        if paged && (dto.limit.is_some() || dto.cursor.is_some()) {
+            errors.push(FieldError::new(
+                "page",
+                "Cannot be combined with limit or cursor",
+            ));
+        }
+        let pagination = if paged {
+            UserPagination::Page {
+                page: dto.page.unwrap_or(1),
+                per_page: dto.per_page.unwrap_or(DEFAULT_PAGE_SIZE),
+            }
+        } else {
+            UserPagination::Cursor {
+                after: dto.cursor,
+                limit: dto.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+            }
+        };
+
+        if !errors.is_empty() {
+            return Err(UserError::Validation(errors));
+        }
+
+        Ok(UserQuery {
+            pagination,
+            sort,
+            direction,
+            email_domain: dto.email_domain.filter(|domain| !domain.is_empty()),
+            name_contains: dto.name_contains.filter(|fragment| !fragment.is_empty()),
+        })
     }
 }
@@ -0,0 +1,45 @@
+use crate::application::dto::read_sessions_input_dto::ReadSessionsInputDto;
+use crate::application::dto::read_sessions_output_dto::{
+    ReadSessionOutputDto, ReadSessionsOutputDto,
+};
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::policies::user_policy::{authorize, UserAction};
+use crate::core::services::session_service::SessionService;
+use crate::core::services::user_service::UserService;
+use tracing::instrument;
+
+pub struct ReadSessionsUseCase {
+    service: Box<dyn UserService>,
+    sessions: Box<dyn SessionService>,
+}
+
+impl ReadSessionsUseCase {
+    pub fn new(service: Box<dyn UserService>, sessions: Box<dyn SessionService>) -> Self {
+        ReadSessionsUseCase { service, sessions }
+    }
+
+    #[instrument(name = "read_sessions", skip_all, fields(user_id = dto.user_id), err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        caller: &Caller,
+        dto: ReadSessionsInputDto,
+    ) -> Result<ReadSessionsOutputDto, UserError> {
+        authorize(caller, UserAction::ListSessions(dto.user_id))?;
+        self.service.find_user_by_id(dto.user_id).await?;
+        let sessions = self.sessions.list_sessions(dto.user_id).await?;
+        Ok(ReadSessionsOutputDto {
+            sessions: sessions
+                .into_iter()
+                .map(|session| ReadSessionOutputDto {
+                    id: session.id,
+                    user_agent: session.user_agent,
+                    ip_address: session.ip_address,
+                    created_at: session.created_at,
+                    last_used_at: session.last_used_at,
+                    expires_at: session.expires_at,
+                })
+                .collect(),
+        })
+    }
+}
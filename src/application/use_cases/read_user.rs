@@ -1,6 +1,10 @@
 use crate::application::dto::read_user_input_dto::ReadUserInputDto;
 use crate::application::dto::read_user_output_dto::ReadUserOutputDto;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::policies::user_policy::{authorize, UserAction};
 use crate::core::services::user_service::UserService;
+use tracing::instrument;
 
 pub struct GetUserUseCase {
     service: Box<dyn UserService>,
@@ -11,14 +15,22 @@ impl GetUserUseCase {
         GetUserUseCase { service }
     }
 
-    pub fn execute(&self, dto: ReadUserInputDto) -> Option<ReadUserOutputDto> {
+    #[instrument(name = "read_user", skip_all, fields(user_id = dto.id), err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        caller: &Caller,
+        dto: ReadUserInputDto,
+    ) -> Result<ReadUserOutputDto, UserError> {
+        authorize(caller, UserAction::Read(dto.id))?;
         let id = dto.id;
         self.service
             .find_user_by_id(id)
+            .await
             .map(|user| ReadUserOutputDto {
                 id: user.id,
                 name: user.name.clone(),
                 email: user.email.clone(),
+                email_verified_at: user.email_verified_at,
             })
     }
 }
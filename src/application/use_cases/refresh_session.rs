@@ -0,0 +1,47 @@
+use crate::application::dto::refresh_session_input_dto::RefreshSessionInputDto;
+use crate::application::dto::refresh_session_output_dto::RefreshSessionOutputDto;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::session_service::SessionService;
+use crate::core::services::token_issuer::TokenIssuer;
+use crate::core::services::user_service::UserService;
+use std::sync::Arc;
+use tracing::instrument;
+
+pub struct RefreshSessionUseCase {
+    service: Box<dyn UserService>,
+    sessions: Box<dyn SessionService>,
+    issuer: Arc<dyn TokenIssuer>,
+}
+
+impl RefreshSessionUseCase {
+    pub fn new(
+        service: Box<dyn UserService>,
+        sessions: Box<dyn SessionService>,
+        issuer: Arc<dyn TokenIssuer>,
+    ) -> Self {
+        RefreshSessionUseCase {
+            service,
+            sessions,
+            issuer,
+        }
+    }
+
+    #[instrument(name = "refresh_session", skip_all, err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        dto: RefreshSessionInputDto,
+    ) -> Result<RefreshSessionOutputDto, UserError> {
+        let grant = self.sessions.refresh(&dto.refresh_token).await?;
+        // Roles are read again so the new access token reflects current grants.
+        let user = match self.service.find_user_by_id(grant.user_id).await {
+            Err(UserError::NotFound) => return Err(UserError::InvalidRefreshToken),
+            user => user?,
+        };
+        let token = self.issuer.issue(&user)?;
+        Ok(RefreshSessionOutputDto {
+            access_token: token.token,
+            expires_in: token.expires_in,
+            refresh_token: grant.refresh_token,
+        })
+    }
+}
@@ -0,0 +1,26 @@
+use crate::application::dto::request_email_verification_input_dto::RequestEmailVerificationInputDto;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::policies::user_policy::{authorize, UserAction};
+use crate::core::services::email_verification_service::EmailVerificationService;
+use tracing::instrument;
+
+pub struct RequestEmailVerificationUseCase {
+    service: Box<dyn EmailVerificationService>,
+}
+
+impl RequestEmailVerificationUseCase {
+    pub fn new(service: Box<dyn EmailVerificationService>) -> Self {
+        RequestEmailVerificationUseCase { service }
+    }
+
+    #[instrument(name = "request_email_verification", skip_all, fields(user_id = dto.user_id), err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        caller: &Caller,
+        dto: RequestEmailVerificationInputDto,
+    ) -> Result<(), UserError> {
+        authorize(caller, UserAction::RequestEmailVerification(dto.user_id))?;
+        self.service.send_verification(dto.user_id).await
+    }
+}
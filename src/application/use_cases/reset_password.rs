@@ -0,0 +1,21 @@
+use crate::application::dto::reset_password_input_dto::ResetPasswordInputDto;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::password_reset_service::PasswordResetService;
+use tracing::instrument;
+
+pub struct ResetPasswordUseCase {
+    service: Box<dyn PasswordResetService>,
+}
+
+impl ResetPasswordUseCase {
+    pub fn new(service: Box<dyn PasswordResetService>) -> Self {
+        ResetPasswordUseCase { service }
+    }
+
+    #[instrument(name = "reset_password", skip_all, err(Display, level = "info"))]
+    pub async fn execute(&self, dto: ResetPasswordInputDto) -> Result<(), UserError> {
+        self.service
+            .reset_password(&dto.token, &dto.new_password)
+            .await
+    }
+}
@@ -0,0 +1,36 @@
+use crate::application::dto::revoke_sessions_input_dto::RevokeSessionsInputDto;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::policies::user_policy::{authorize, UserAction};
+use crate::core::services::session_service::SessionService;
+use crate::core::services::user_service::UserService;
+use tracing::instrument;
+
+pub struct RevokeSessionsUseCase {
+    service: Box<dyn UserService>,
+    sessions: Box<dyn SessionService>,
+}
+
+impl RevokeSessionsUseCase {
+    pub fn new(service: Box<dyn UserService>, sessions: Box<dyn SessionService>) -> Self {
+        RevokeSessionsUseCase { service, sessions }
+    }
+
+    #[instrument(name = "revoke_sessions", skip_all, fields(user_id = dto.user_id), err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        caller: &Caller,
+        dto: RevokeSessionsInputDto,
+    ) -> Result<(), UserError> {
+        authorize(caller, UserAction::RevokeSessions(dto.user_id))?;
+        self.service.find_user_by_id(dto.user_id).await?;
+        match dto.session_id {
+            Some(session_id) => self.sessions.revoke_session(dto.user_id, session_id).await,
+            None => self
+                .sessions
+                .revoke_all_sessions(dto.user_id)
+                .await
+                .map(|_| ()),
+        }
+    }
+}
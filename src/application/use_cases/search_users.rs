@@ -0,0 +1,41 @@
+use crate::application::dto::search_users_input_dto::SearchUsersInputDto;
+use crate::application::dto::search_users_output_dto::SearchUsersOutputDto;
+use crate::core::domain::caller::Caller;
+use crate::core::domain::user_search::UserSearch;
+use crate::core::errors::user_error::UserError;
+use crate::core::policies::user_policy::{authorize, UserAction};
+use crate::core::services::user_service::UserService;
+use tracing::instrument;
+
+pub struct SearchUsersUseCase {
+    service: Box<dyn UserService>,
+}
+
+impl SearchUsersUseCase {
+    pub fn new(service: Box<dyn UserService>) -> Self {
+        SearchUsersUseCase { service }
+    }
+
+    #[instrument(name = "search_users", skip_all, fields(limit = dto.limit), err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        caller: &Caller,
+        dto: SearchUsersInputDto,
+    ) -> Result<Vec<SearchUsersOutputDto>, UserError> {
+        authorize(caller, UserAction::Search)?;
+        let search = UserSearch::new(&dto.query, dto.limit);
+        Ok(self
+            .service
+            .search_users(&search)
+            .await?
+            .into_iter()
+            .map(|user_match| SearchUsersOutputDto {
+                id: user_match.user.id,
+                name: user_match.user.name,
+                email: user_match.user.email,
+                email_verified_at: user_match.user.email_verified_at,
+                score: user_match.score,
+            })
+            .collect())
+    }
+}
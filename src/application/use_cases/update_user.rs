@@ -1,6 +1,10 @@
 use crate::application::dto::update_user_input_dto::UpdateUserInputDto;
 use crate::application::dto::update_user_output_dto::UpdateUserOutputDto;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::policies::user_policy::{authorize, UserAction};
 use crate::core::services::user_service::UserService;
+use tracing::instrument;
 
 pub struct UpdateUserUseCase {
     service: Box<dyn UserService>,
@@ -11,13 +15,21 @@ impl UpdateUserUseCase {
         UpdateUserUseCase { service }
     }
 
-    pub fn execute(&self, dto: UpdateUserInputDto) -> Result<UpdateUserOutputDto, String> {
+    #[instrument(name = "update_user", skip_all, fields(user_id = dto.id), err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        caller: &Caller,
+        dto: UpdateUserInputDto,
+    ) -> Result<UpdateUserOutputDto, UserError> {
+        authorize(caller, UserAction::Update(dto.id))?;
         self.service
             .update_user(dto.id, dto.name, dto.email)
+            .await
             .map(|user| UpdateUserOutputDto {
                 id: user.id,
                 name: user.name,
                 email: user.email,
+                email_verified_at: user.email_verified_at,
             })
     }
 }
@@ -0,0 +1,31 @@
+use crate::application::dto::verify_email_input_dto::VerifyEmailInputDto;
+use crate::application::dto::verify_email_output_dto::VerifyEmailOutputDto;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::email_verification_service::EmailVerificationService;
+use tracing::instrument;
+
+pub struct VerifyEmailUseCase {
+    service: Box<dyn EmailVerificationService>,
+}
+
+impl VerifyEmailUseCase {
+    pub fn new(service: Box<dyn EmailVerificationService>) -> Self {
+        VerifyEmailUseCase { service }
+    }
+
+    /// Opened straight from a mail client, so there is no bearer token to authorize.
+    #[instrument(name = "verify_email", skip_all, err(Display, level = "info"))]
+    pub async fn execute(
+        &self,
+        dto: VerifyEmailInputDto,
+    ) -> Result<VerifyEmailOutputDto, UserError> {
+        self.service
+            .verify(&dto.token)
+            .await
+            .map(|user| VerifyEmailOutputDto {
+                id: user.id,
+                email: user.email,
+                email_verified_at: user.email_verified_at,
+            })
+    }
+}
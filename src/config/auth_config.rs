@@ -0,0 +1,112 @@
+use crate::config::sources::{ConfigIssue, ConfigSources};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// RFC 7518 asks for an HS256 key at least as long as the hash output.
+const MIN_SECRET_BYTES: usize = 32;
+const DEFAULT_LEEWAY_SECS: u64 = 60;
+const DEFAULT_TOKEN_TTL_SECS: u64 = 15 * 60;
+const DEFAULT_REFRESH_TOKEN_TTL_SECS: u64 = 30 * 24 * 60 * 60;
+
+#[derive(Clone, Debug)]
+pub struct AuthConfig {
+    /// Shared secret for HS256 tokens.
+    pub jwt_secret: Option<String>,
+    /// Local JWKS document holding the public keys for RS256 tokens.
+    pub jwks_file: Option<PathBuf>,
+    pub issuer: String,
+    pub audience: String,
+    /// Clock skew tolerated when checking `exp` and `nbf`.
+    pub leeway: Duration,
+    /// Lifetime of the access tokens issued by `/auth/login`.
+    pub token_ttl: Duration,
+    /// How long a session lasts after login; refreshing does not extend it.
+    pub refresh_token_ttl: Duration,
+    /// PEM RSA private key; when set, issued tokens are RS256 instead of HS256.
+    pub signing_key_file: Option<PathBuf>,
+    /// `kid` put in the header of RS256 tokens, matching the key in the JWKS.
+    pub signing_key_id: Option<String>,
+    /// Account made an admin at startup, created without a password if missing.
+    pub initial_admin_email: Option<String>,
+}
+
+impl AuthConfig {
+    pub fn load(sources: &ConfigSources, issues: &mut Vec<ConfigIssue>) -> Self {
+        let jwt_secret = sources.get("auth.jwt_secret").map(str::to_string);
+        if jwt_secret
+            .as_ref()
+            .is_some_and(|secret| secret.len() < MIN_SECRET_BYTES)
+        {
+            issues.push(ConfigIssue::new(
+                "auth.jwt_secret",
+                &format!("Must be at least {} bytes", MIN_SECRET_BYTES),
+            ));
+        }
+
+        AuthConfig {
+            jwt_secret,
+            jwks_file: sources.get("auth.jwks_file").map(PathBuf::from),
+            issuer: sources.string_or("auth.issuer", "user-api"),
+            audience: sources.string_or("auth.audience", "user-api"),
+            leeway: Duration::from_secs(sources.parse_or(
+                "auth.leeway",
+                DEFAULT_LEEWAY_SECS,
+                "a whole number of seconds",
+                issues,
+            )),
+            token_ttl: Duration::from_secs(sources.parse_or(
+                "auth.token_ttl",
+                DEFAULT_TOKEN_TTL_SECS,
+                "a whole number of seconds",
+                issues,
+            )),
+            refresh_token_ttl: Duration::from_secs(sources.parse_or(
+                "auth.refresh_token_ttl",
+                DEFAULT_REFRESH_TOKEN_TTL_SECS,
+                "a whole number of seconds",
+                issues,
+            )),
+            signing_key_file: sources.get("auth.signing_key_file").map(PathBuf::from),
+            signing_key_id: sources.get("auth.signing_key_id").map(str::to_string),
+            initial_admin_email: sources.get("auth.initial_admin_email").map(str::to_string),
+        }
+    }
+
+    pub fn has_keys(&self) -> bool {
+        self.jwt_secret.is_some() || self.jwks_file.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(toml: &str) -> (AuthConfig, Vec<ConfigIssue>) {
+        let mut issues = Vec::new();
+        let config = AuthConfig::load(&ConfigSources::default().with_toml(toml), &mut issues);
+        (config, issues)
+    }
+
+    #[test]
+    fn test_defaults() {
+        let (config, issues) = load("");
+        assert!(issues.is_empty());
+        assert!(!config.has_keys());
+        assert_eq!(config.issuer, "user-api");
+        assert_eq!(config.audience, "user-api");
+        assert_eq!(config.leeway, Duration::from_secs(60));
+        assert_eq!(config.initial_admin_email, None);
+        assert_eq!(
+            config.refresh_token_ttl,
+            Duration::from_secs(30 * 24 * 60 * 60)
+        );
+    }
+
+    #[test]
+    fn test_short_secrets_are_rejected() {
+        let (config, issues) = load("[auth]\njwt_secret = \"too-short\"\n");
+        assert!(config.has_keys());
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].key, "auth.jwt_secret");
+    }
+}
@@ -0,0 +1,53 @@
+use crate::config::sources::ConfigSources;
+use clap::Parser;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(name = "user-api", about = "User management HTTP API")]
+pub struct Cli {
+    /// TOML or YAML (.yaml/.yml) configuration file; environment variables and flags override it
+    #[arg(long, env = "CONFIG_FILE", value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Port to listen on (same as --set server.port=PORT)
+    #[arg(long)]
+    pub port: Option<String>,
+
+    /// Database connection URL (same as --set database.url=URL)
+    #[arg(long, value_name = "URL")]
+    pub database_url: Option<String>,
+
+    /// Override any configuration key, e.g. --set database.host=db
+    #[arg(long = "set", value_name = "KEY=VALUE", value_parser = parse_override)]
+    pub overrides: Vec<(String, String)>,
+
+    /// Print the effective configuration, with secrets redacted, and exit
+    #[arg(long)]
+    pub print_config: bool,
+}
+
+impl Cli {
+    pub fn sources(&self) -> ConfigSources {
+        let mut overrides = Vec::new();
+        if let Some(port) = &self.port {
+            overrides.push(("server.port".to_string(), port.clone()));
+        }
+        if let Some(url) = &self.database_url {
+            overrides.push(("database.url".to_string(), url.clone()));
+        }
+        overrides.extend(self.overrides.iter().cloned());
+
+        let sources = match &self.config {
+            Some(path) => ConfigSources::default().with_file(path),
+            None => ConfigSources::default(),
+        };
+        sources.with_env().with_overrides(&overrides)
+    }
+}
+
+fn parse_override(value: &str) -> Result<(String, String), String> {
+    value
+        .split_once('=')
+        .map(|(key, value)| (key.trim().to_string(), value.to_string()))
+        .ok_or_else(|| format!("expected KEY=VALUE, got `{}`", value))
+}
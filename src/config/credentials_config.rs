@@ -0,0 +1,86 @@
+use crate::config::sources::{ConfigIssue, ConfigSources};
+use argon2::Params;
+use std::fmt;
+
+/// Argon2id cost. The defaults follow the OWASP minimum of 19 MiB, two passes
+/// and one lane.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CredentialsConfig {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl CredentialsConfig {
+    pub fn load(sources: &ConfigSources, issues: &mut Vec<ConfigIssue>) -> Self {
+        let config = CredentialsConfig {
+            memory_kib: sources.parse_or(
+                "credentials.memory_kib",
+                Params::DEFAULT_M_COST,
+                "a number of KiB",
+                issues,
+            ),
+            iterations: sources.parse_or(
+                "credentials.iterations",
+                Params::DEFAULT_T_COST,
+                "a number",
+                issues,
+            ),
+            parallelism: sources.parse_or(
+                "credentials.parallelism",
+                Params::DEFAULT_P_COST,
+                "a number",
+                issues,
+            ),
+        };
+        if let Err(error) = config.params() {
+            issues.push(ConfigIssue::new(
+                "credentials",
+                &format!("Invalid Argon2 cost: {}", error),
+            ));
+        }
+        config
+    }
+
+    pub fn params(&self) -> Result<Params, argon2::Error> {
+        Params::new(self.memory_kib, self.iterations, self.parallelism, None)
+    }
+}
+
+impl fmt::Display for CredentialsConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "argon2id memory_kib={} iterations={} parallelism={}",
+            self.memory_kib, self.iterations, self.parallelism
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(toml: &str) -> (CredentialsConfig, Vec<ConfigIssue>) {
+        let mut issues = Vec::new();
+        let config =
+            CredentialsConfig::load(&ConfigSources::default().with_toml(toml), &mut issues);
+        (config, issues)
+    }
+
+    #[test]
+    fn test_defaults() {
+        let (config, issues) = load("");
+        assert!(issues.is_empty());
+        assert_eq!(config.memory_kib, 19 * 1024);
+        assert_eq!(config.iterations, 2);
+        assert_eq!(config.parallelism, 1);
+    }
+
+    #[test]
+    fn test_rejects_costs_argon2_cannot_use() {
+        let (_, issues) = load("[credentials]\niterations = 0\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].key, "credentials");
+    }
+}
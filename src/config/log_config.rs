@@ -0,0 +1,85 @@
+use crate::config::sources::{ConfigIssue, ConfigSources};
+use std::fmt;
+use tracing_subscriber::EnvFilter;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LogFormat {
+    Pretty,
+    Json,
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogFormat::Pretty => write!(f, "pretty"),
+            LogFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LogConfig {
+    /// An `EnvFilter` directive such as `info` or `user_api=debug,actix_web=warn`.
+    pub level: String,
+    pub format: LogFormat,
+}
+
+impl LogConfig {
+    pub fn load(sources: &ConfigSources, issues: &mut Vec<ConfigIssue>) -> Self {
+        let level = sources.string_or("log.level", "info");
+        if EnvFilter::try_new(&level).is_err() {
+            issues.push(ConfigIssue::new(
+                "log.level",
+                "Must be a level or filter directive, e.g. info or user_api=debug",
+            ));
+        }
+
+        let format = match sources.get("log.format") {
+            None | Some("pretty") => LogFormat::Pretty,
+            Some("json") => LogFormat::Json,
+            Some(_) => {
+                issues.push(ConfigIssue::new("log.format", "Must be pretty or json"));
+                LogFormat::Pretty
+            }
+        };
+
+        LogConfig { level, format }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(toml: &str) -> (LogConfig, Vec<ConfigIssue>) {
+        let mut issues = Vec::new();
+        let config = LogConfig::load(&ConfigSources::default().with_toml(toml), &mut issues);
+        (config, issues)
+    }
+
+    #[test]
+    fn test_defaults() {
+        let (config, issues) = load("");
+        assert!(issues.is_empty());
+        assert_eq!(config.level, "info");
+        assert_eq!(config.format, LogFormat::Pretty);
+    }
+
+    #[test]
+    fn test_filter_directives_are_accepted() {
+        let (config, issues) =
+            load("[log]\nlevel = \"user_api=debug,actix_web=warn\"\nformat = \"json\"\n");
+        assert!(issues.is_empty());
+        assert_eq!(config.format, LogFormat::Json);
+    }
+
+    #[test]
+    fn test_invalid_values_are_reported() {
+        let (_, issues) = load("[log]\nlevel = \"user_api=loud\"\nformat = \"xml\"\n");
+        let keys = issues
+            .iter()
+            .map(|issue| issue.key.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(keys, vec!["log.level", "log.format"]);
+    }
+}
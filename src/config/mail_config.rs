@@ -0,0 +1,191 @@
+use crate::config::sources::{ConfigIssue, ConfigSources};
+use std::fmt;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const DEFAULT_SMTP_PORT: u16 = 587;
+const DEFAULT_VERIFICATION_TTL_SECS: u64 = 24 * 60 * 60;
+const DEFAULT_PASSWORD_RESET_TTL_SECS: u64 = 60 * 60;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MailTransport {
+    /// Kept in process; only useful for development.
+    Memory,
+    /// Appended as JSON lines to `outbox_file`, for tests and local inspection.
+    File,
+    Smtp,
+}
+
+impl fmt::Display for MailTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MailTransport::Memory => write!(f, "memory"),
+            MailTransport::File => write!(f, "file"),
+            MailTransport::Smtp => write!(f, "smtp"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SmtpTls {
+    /// Plain connection; only for relays on a trusted network.
+    None,
+    StartTls,
+    /// Implicit TLS, usually on port 465.
+    Tls,
+}
+
+impl fmt::Display for SmtpTls {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SmtpTls::None => write!(f, "none"),
+            SmtpTls::StartTls => write!(f, "starttls"),
+            SmtpTls::Tls => write!(f, "tls"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub tls: SmtpTls,
+}
+
+#[derive(Clone, Debug)]
+pub struct MailConfig {
+    pub transport: MailTransport,
+    /// The `From` mailbox, e.g. `User API <no-reply@example.com>`.
+    pub from: String,
+    /// Base URL that links in emails point at.
+    pub public_url: String,
+    pub outbox_file: Option<PathBuf>,
+    pub smtp: Option<SmtpConfig>,
+    /// How long an email verification link stays valid.
+    pub verification_ttl: Duration,
+    /// How long a password reset link stays valid.
+    pub password_reset_ttl: Duration,
+}
+
+impl MailConfig {
+    pub fn load(sources: &ConfigSources, issues: &mut Vec<ConfigIssue>) -> Self {
+        let transport = match sources.get("mail.transport") {
+            None | Some("memory") => MailTransport::Memory,
+            Some("file") => MailTransport::File,
+            Some("smtp") => MailTransport::Smtp,
+            Some(_) => {
+                issues.push(ConfigIssue::new(
+                    "mail.transport",
+                    "Must be memory, file or smtp",
+                ));
+                MailTransport::Memory
+            }
+        };
+
+        let outbox_file = sources.get("mail.outbox_file").map(PathBuf::from);
+        if transport == MailTransport::File && outbox_file.is_none() {
+            issues.push(ConfigIssue::new(
+                "mail.outbox_file",
+                "Required when mail.transport is file",
+            ));
+        }
+
+        let tls = match sources.get("mail.smtp_tls") {
+            None | Some("starttls") => SmtpTls::StartTls,
+            Some("tls") => SmtpTls::Tls,
+            Some("none") => SmtpTls::None,
+            Some(_) => {
+                issues.push(ConfigIssue::new(
+                    "mail.smtp_tls",
+                    "Must be starttls, tls or none",
+                ));
+                SmtpTls::StartTls
+            }
+        };
+        let port = sources.parse_or("mail.smtp_port", DEFAULT_SMTP_PORT, "a port number", issues);
+        let smtp = match sources.get("mail.smtp_host") {
+            Some(host) => Some(SmtpConfig {
+                host: host.to_string(),
+                port,
+                username: sources.get("mail.smtp_username").map(str::to_string),
+                password: sources.get("mail.smtp_password").map(str::to_string),
+                tls,
+            }),
+            None if transport == MailTransport::Smtp => {
+                issues.push(ConfigIssue::new(
+                    "mail.smtp_host",
+                    "Required when mail.transport is smtp",
+                ));
+                None
+            }
+            None => None,
+        };
+
+        MailConfig {
+            transport,
+            from: sources.string_or("mail.from", "User API <no-reply@localhost>"),
+            public_url: sources
+                .string_or("mail.public_url", "http://localhost:8080")
+                .trim_end_matches('/')
+                .to_string(),
+            outbox_file,
+            smtp,
+            verification_ttl: Duration::from_secs(sources.parse_or(
+                "mail.verification_ttl",
+                DEFAULT_VERIFICATION_TTL_SECS,
+                "a whole number of seconds",
+                issues,
+            )),
+            password_reset_ttl: Duration::from_secs(sources.parse_or(
+                "mail.password_reset_ttl",
+                DEFAULT_PASSWORD_RESET_TTL_SECS,
+                "a whole number of seconds",
+                issues,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(toml: &str) -> (MailConfig, Vec<ConfigIssue>) {
+        let mut issues = Vec::new();
+        let config = MailConfig::load(&ConfigSources::default().with_toml(toml), &mut issues);
+        (config, issues)
+    }
+
+    #[test]
+    fn test_defaults() {
+        let (config, issues) = load("");
+        assert!(issues.is_empty());
+        assert_eq!(config.transport, MailTransport::Memory);
+        assert_eq!(config.public_url, "http://localhost:8080");
+        assert_eq!(config.verification_ttl, Duration::from_secs(86400));
+        assert_eq!(config.password_reset_ttl, Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn test_transports_require_their_settings() {
+        let (_, issues) = load("[mail]\ntransport = \"smtp\"\n");
+        assert_eq!(issues[0].key, "mail.smtp_host");
+        let (_, issues) = load("[mail]\ntransport = \"file\"\n");
+        assert_eq!(issues[0].key, "mail.outbox_file");
+    }
+
+    #[test]
+    fn test_smtp_settings() {
+        let (config, issues) = load(
+            "[mail]\ntransport = \"smtp\"\nsmtp_host = \"smtp.example.com\"\n\
+             smtp_port = 465\nsmtp_tls = \"tls\"\npublic_url = \"https://users.example.com/\"\n",
+        );
+        assert!(issues.is_empty());
+        let smtp = config.smtp.unwrap();
+        assert_eq!((smtp.host.as_str(), smtp.port), ("smtp.example.com", 465));
+        assert_eq!(smtp.tls, SmtpTls::Tls);
+        assert_eq!(config.public_url, "https://users.example.com");
+    }
+}
@@ -1,3 +1,10 @@
+pub mod auth_config;
+pub mod cli;
+pub mod credentials_config;
 pub mod database_config;
 pub mod env;
+pub mod log_config;
+pub mod mail_config;
+pub mod server_config;
 pub mod settings;
+pub mod sources;
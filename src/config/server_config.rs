@@ -0,0 +1,146 @@
+use crate::config::sources::{ConfigIssue, ConfigSources};
+use std::path::PathBuf;
+use std::time::Duration;
+
+const DEFAULT_PORT: u16 = 8080;
+const DEFAULT_KEEP_ALIVE_SECS: u64 = 5;
+const DEFAULT_BACKLOG: u32 = 2048;
+const DEFAULT_MAX_CONNECTIONS: usize = 25_000;
+const DEFAULT_SHUTDOWN_TIMEOUT_SECS: u64 = 30;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+}
+
+#[derive(Clone, Debug)]
+pub struct ServerConfig {
+    pub host: String,
+    pub port: u16,
+    /// `None` keeps actix's default of one worker per physical core.
+    pub workers: Option<usize>,
+    pub keep_alive: Duration,
+    pub backlog: u32,
+    pub max_connections: usize,
+    pub tls: Option<TlsConfig>,
+    /// How long to keep accepting connections, with `/readyz` failing, after a
+    /// shutdown signal, so load balancers stop routing here first.
+    pub shutdown_delay: Duration,
+    /// Deadline for in-flight requests to finish once the listener is closed.
+    pub shutdown_timeout: Duration,
+}
+
+impl ServerConfig {
+    pub fn load(sources: &ConfigSources, issues: &mut Vec<ConfigIssue>) -> Self {
+        let workers = match sources.get("server.workers") {
+            None => None,
+            Some(value) => match value.parse::<usize>() {
+                Ok(workers) if workers > 0 => Some(workers),
+                _ => {
+                    issues.push(ConfigIssue::new(
+                        "server.workers",
+                        "Must be a positive number",
+                    ));
+                    None
+                }
+            },
+        };
+
+        let tls = match (
+            sources.get("server.tls_cert"),
+            sources.get("server.tls_key"),
+        ) {
+            (None, None) => None,
+            (Some(cert_path), Some(key_path)) => Some(TlsConfig {
+                cert_path: PathBuf::from(cert_path),
+                key_path: PathBuf::from(key_path),
+            }),
+            (Some(_), None) => {
+                issues.push(ConfigIssue::new(
+                    "server.tls_key",
+                    "Required when server.tls_cert is set",
+                ));
+                None
+            }
+            (None, Some(_)) => {
+                issues.push(ConfigIssue::new(
+                    "server.tls_cert",
+                    "Required when server.tls_key is set",
+                ));
+                None
+            }
+        };
+
+        ServerConfig {
+            host: sources.string_or("server.host", "127.0.0.1"),
+            port: sources.parse_or("server.port", DEFAULT_PORT, "a port number", issues),
+            workers,
+            keep_alive: Duration::from_secs(sources.parse_or(
+                "server.keep_alive",
+                DEFAULT_KEEP_ALIVE_SECS,
+                "a whole number of seconds",
+                issues,
+            )),
+            backlog: sources.parse_or("server.backlog", DEFAULT_BACKLOG, "a number", issues),
+            max_connections: sources.parse_or(
+                "server.max_connections",
+                DEFAULT_MAX_CONNECTIONS,
+                "a number",
+                issues,
+            ),
+            tls,
+            shutdown_delay: Duration::from_secs(sources.parse_or(
+                "server.shutdown_delay",
+                0,
+                "a whole number of seconds",
+                issues,
+            )),
+            shutdown_timeout: Duration::from_secs(sources.parse_or(
+                "server.shutdown_timeout",
+                DEFAULT_SHUTDOWN_TIMEOUT_SECS,
+                "a whole number of seconds",
+                issues,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(toml: &str) -> (ServerConfig, Vec<ConfigIssue>) {
+        let mut issues = Vec::new();
+        let config = ServerConfig::load(&ConfigSources::default().with_toml(toml), &mut issues);
+        (config, issues)
+    }
+
+    #[test]
+    fn test_defaults() {
+        let (config, issues) = load("");
+        assert!(issues.is_empty());
+        assert_eq!(config.host, "127.0.0.1");
+        assert_eq!(config.workers, None);
+        assert_eq!(config.tls, None);
+    }
+
+    #[test]
+    fn test_tls_requires_both_paths() {
+        let (config, issues) = load("[server]\ntls_cert = \"cert.pem\"\n");
+        assert_eq!(config.tls, None);
+        assert_eq!(
+            issues,
+            vec![ConfigIssue::new(
+                "server.tls_key",
+                "Required when server.tls_cert is set"
+            )]
+        );
+    }
+
+    #[test]
+    fn test_zero_workers_is_rejected() {
+        let (_, issues) = load("[server]\nworkers = 0\n");
+        assert_eq!(issues[0].key, "server.workers");
+    }
+}
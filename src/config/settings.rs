@@ -1,25 +1,239 @@
+use crate::config::auth_config::AuthConfig;
+use crate::config::credentials_config::CredentialsConfig;
 use crate::config::database_config::DatabaseConfig;
+use crate::config::log_config::LogConfig;
+use crate::config::mail_config::{MailConfig, MailTransport};
+use crate::config::server_config::ServerConfig;
+use crate::config::sources::{ConfigError, ConfigIssue, ConfigSources};
+use crate::infrastructure::database::memory::memory_database::MemoryDatabase;
 use crate::infrastructure::database::postgres::database_manager::ConnectionPool;
-use std::env;
+use std::fmt;
 
 #[derive(Clone)]
 pub struct Settings {
     pub environment: String,
-    pub port: u16,
+    pub server_config: ServerConfig,
     pub database_config: DatabaseConfig,
+    pub auth_config: AuthConfig,
+    pub credentials_config: CredentialsConfig,
+    pub log_config: LogConfig,
+    pub mail_config: MailConfig,
     pub connection_pool: Option<ConnectionPool>,
+    pub memory_database: MemoryDatabase,
+    pub dev_docker_compose: bool,
 }
 
 impl Settings {
-    pub fn new() -> Self {
-        Self {
-            environment: env::var("ENVIRONMENT").unwrap_or_else(|_| "development".to_string()),
-            port: env::var("PORT")
-                .unwrap_or_else(|_| "8080".to_string())
-                .parse::<u16>()
-                .unwrap(),
-            database_config: DatabaseConfig::new(),
+    /// Builds settings from layered sources, reporting every invalid key at once.
+    pub fn load(sources: &ConfigSources) -> Result<Self, ConfigError> {
+        let mut issues = sources.issues().to_vec();
+
+        let environment = sources.string_or("environment", "development");
+        let server_config = ServerConfig::load(sources, &mut issues);
+        let database_config = DatabaseConfig::load(sources, &mut issues);
+        let auth_config = AuthConfig::load(sources, &mut issues);
+        if !auth_config.has_keys() && environment != "development" {
+            issues.push(ConfigIssue::new(
+                "auth.jwt_secret",
+                "Set auth.jwt_secret or auth.jwks_file outside the development environment",
+            ));
+        }
+        let credentials_config = CredentialsConfig::load(sources, &mut issues);
+        let log_config = LogConfig::load(sources, &mut issues);
+        let mail_config = MailConfig::load(sources, &mut issues);
+        if mail_config.transport == MailTransport::Memory && environment != "development" {
+            issues.push(ConfigIssue::new(
+                "mail.transport",
+                "Set mail.transport to smtp or file outside the development environment",
+            ));
+        }
+        let dev_docker_compose = sources.bool_or("dev.docker_compose", false, &mut issues);
+        if dev_docker_compose && environment != "development" {
+            issues.push(ConfigIssue::new(
+                "dev.docker_compose",
+                "Only allowed in the development environment",
+            ));
+        }
+
+        if !issues.is_empty() {
+            return Err(ConfigError { issues });
+        }
+
+        Ok(Self {
+            environment,
+            server_config,
+            database_config,
+            auth_config,
+            credentials_config,
+            log_config,
+            mail_config,
             connection_pool: None,
+            memory_database: MemoryDatabase::new(),
+            dev_docker_compose,
+        })
+    }
+
+    #[cfg(test)]
+    pub fn from_env() -> Self {
+        Self::load(&ConfigSources::default().with_env()).expect("Invalid test configuration")
+    }
+}
+
+/// The effective configuration with secrets redacted, one dotted key per line.
+impl fmt::Display for Settings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let server = &self.server_config;
+        let database = &self.database_config;
+        let auth = &self.auth_config;
+        let mail = &self.mail_config;
+        let workers = server
+            .workers
+            .map_or("default".to_string(), |workers| workers.to_string());
+        let tls = server.tls.as_ref().map_or("disabled".to_string(), |tls| {
+            format!("{} / {}", tls.cert_path.display(), tls.key_path.display())
+        });
+        // Credentials stay out; only where mail goes is shown.
+        let smtp = mail.smtp.as_ref().map_or("unset".to_string(), |smtp| {
+            format!("{}:{} ({})", smtp.host, smtp.port, smtp.tls)
+        });
+        let lines = [
+            ("environment", self.environment.clone()),
+            ("server.host", server.host.clone()),
+            ("server.port", server.port.to_string()),
+            ("server.workers", workers),
+            (
+                "server.keep_alive",
+                format!("{}s", server.keep_alive.as_secs()),
+            ),
+            ("server.backlog", server.backlog.to_string()),
+            ("server.max_connections", server.max_connections.to_string()),
+            ("server.tls", tls),
+            (
+                "server.shutdown_delay",
+                format!("{}s", server.shutdown_delay.as_secs()),
+            ),
+            (
+                "server.shutdown_timeout",
+                format!("{}s", server.shutdown_timeout.as_secs()),
+            ),
+            ("database.type", database.database_type.to_string()),
+            ("database.url", database.redacted_url()),
+            ("database.pool", database.pool.to_string()),
+            (
+                "database.connect_timeout",
+                format!("{}s", database.connect_timeout.as_secs()),
+            ),
+            (
+                "auth.jwt_secret",
+                auth.jwt_secret
+                    .as_ref()
+                    .map_or("unset", |_| "********")
+                    .to_string(),
+            ),
+            (
+                "auth.jwks_file",
+                auth.jwks_file
+                    .as_ref()
+                    .map_or("unset".to_string(), |path| path.display().to_string()),
+            ),
+            ("auth.issuer", auth.issuer.clone()),
+            ("auth.audience", auth.audience.clone()),
+            ("auth.leeway", format!("{}s", auth.leeway.as_secs())),
+            ("auth.token_ttl", format!("{}s", auth.token_ttl.as_secs())),
+            (
+                "auth.refresh_token_ttl",
+                format!("{}s", auth.refresh_token_ttl.as_secs()),
+            ),
+            (
+                "auth.signing_key_file",
+                auth.signing_key_file
+                    .as_ref()
+                    .map_or("unset".to_string(), |path| path.display().to_string()),
+            ),
+            (
+                "auth.initial_admin_email",
+                auth.initial_admin_email
+                    .clone()
+                    .unwrap_or("unset".to_string()),
+            ),
+            ("credentials", self.credentials_config.to_string()),
+            ("mail.transport", mail.transport.to_string()),
+            ("mail.from", mail.from.clone()),
+            ("mail.public_url", mail.public_url.clone()),
+            ("mail.smtp", smtp),
+            ("log.level", self.log_config.level.clone()),
+            ("log.format", self.log_config.format.to_string()),
+            ("dev.docker_compose", self.dev_docker_compose.to_string()),
+        ];
+        for (key, value) in lines {
+            writeln!(f, "{:.<24}: {}", key, value)?;
         }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(toml: &str) -> Result<Settings, ConfigError> {
+        Settings::load(&ConfigSources::default().with_toml(toml))
+    }
+
+    #[test]
+    fn test_defaults() {
+        let settings = load("").unwrap();
+        assert_eq!(settings.environment, "development");
+        assert_eq!(settings.server_config.port, 8080);
+        assert!(!settings.dev_docker_compose);
+    }
+
+    #[test]
+    fn test_every_invalid_key_is_reported() {
+        let error = load(
+            "environment = \"production\"\n[server]\nport = 70000\n\
+             [database]\ntype = \"sqlite\"\n[dev]\ndocker_compose = true\n",
+        )
+        .err()
+        .unwrap();
+        let keys = error
+            .issues
+            .iter()
+            .map(|issue| issue.key.as_str())
+            .collect::<Vec<&str>>();
+        assert_eq!(
+            keys,
+            vec![
+                "server.port",
+                "database.type",
+                "auth.jwt_secret",
+                "mail.transport",
+                "dev.docker_compose"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_production_accepts_a_jwks_file() {
+        let settings = load(
+            "environment = \"production\"\n[auth]\njwks_file = \"jwks.json\"\n\
+             [mail]\ntransport = \"smtp\"\nsmtp_host = \"smtp.example.com\"\n",
+        );
+        assert!(settings.is_ok());
+    }
+
+    #[test]
+    fn test_display_redacts_secrets() {
+        let settings = load(
+            "[database]\npassword = \"hunter2\"\n\
+             [auth]\njwt_secret = \"correct-horse-battery-staple-0123\"\n\
+             [mail]\nsmtp_host = \"smtp.example.com\"\nsmtp_password = \"swordfish\"\n",
+        )
+        .unwrap();
+        let printed = settings.to_string();
+        assert!(!printed.contains("hunter2"));
+        assert!(!printed.contains("swordfish"));
+        assert!(!printed.contains("correct-horse"));
+        assert!(printed.contains("database.url............: postgres://postgres:********@"));
     }
 }
@@ -0,0 +1,307 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Every supported configuration key and the environment variable that overrides it.
+pub const KEYS: &[(&str, &str)] = &[
+    ("environment", "ENVIRONMENT"),
+    ("server.host", "HOST"),
+    ("server.port", "PORT"),
+    ("server.workers", "WORKERS"),
+    ("server.keep_alive", "KEEP_ALIVE"),
+    ("server.backlog", "BACKLOG"),
+    ("server.max_connections", "MAX_CONNECTIONS"),
+    ("server.tls_cert", "TLS_CERT"),
+    ("server.tls_key", "TLS_KEY"),
+    ("server.shutdown_delay", "SHUTDOWN_DELAY"),
+    ("server.shutdown_timeout", "SHUTDOWN_TIMEOUT"),
+    ("database.type", "DB_TYPE"),
+    ("database.url", "DATABASE_URL"),
+    ("database.host", "DB_HOST"),
+    ("database.port", "DB_PORT"),
+    ("database.user", "DB_USER"),
+    ("database.password", "DB_PASSWORD"),
+    ("database.name", "DB_DATABASE"),
+    ("database.sslmode", "DB_SSLMODE"),
+    ("database.connect_timeout", "DB_CONNECT_TIMEOUT"),
+    ("database.pool.max_size", "DB_POOL_MAX_SIZE"),
+    ("database.pool.min_idle", "DB_POOL_MIN_IDLE"),
+    (
+        "database.pool.connection_timeout",
+        "DB_POOL_CONNECTION_TIMEOUT",
+    ),
+    ("database.pool.idle_timeout", "DB_POOL_IDLE_TIMEOUT"),
+    ("database.pool.max_lifetime", "DB_POOL_MAX_LIFETIME"),
+    ("database.pool.test_on_checkout", "DB_POOL_TEST_ON_CHECKOUT"),
+    ("auth.jwt_secret", "AUTH_JWT_SECRET"),
+    ("auth.jwks_file", "AUTH_JWKS_FILE"),
+    ("auth.issuer", "AUTH_ISSUER"),
+    ("auth.audience", "AUTH_AUDIENCE"),
+    ("auth.leeway", "AUTH_LEEWAY"),
+    ("auth.token_ttl", "AUTH_TOKEN_TTL"),
+    ("auth.refresh_token_ttl", "AUTH_REFRESH_TOKEN_TTL"),
+    ("auth.signing_key_file", "AUTH_SIGNING_KEY_FILE"),
+    ("auth.signing_key_id", "AUTH_SIGNING_KEY_ID"),
+    ("auth.initial_admin_email", "AUTH_INITIAL_ADMIN_EMAIL"),
+    ("credentials.memory_kib", "ARGON2_MEMORY_KIB"),
+    ("credentials.iterations", "ARGON2_ITERATIONS"),
+    ("credentials.parallelism", "ARGON2_PARALLELISM"),
+    ("mail.transport", "MAIL_TRANSPORT"),
+    ("mail.from", "MAIL_FROM"),
+    ("mail.public_url", "PUBLIC_URL"),
+    ("mail.outbox_file", "MAIL_OUTBOX_FILE"),
+    ("mail.smtp_host", "SMTP_HOST"),
+    ("mail.smtp_port", "SMTP_PORT"),
+    ("mail.smtp_username", "SMTP_USERNAME"),
+    ("mail.smtp_password", "SMTP_PASSWORD"),
+    ("mail.smtp_tls", "SMTP_TLS"),
+    ("mail.verification_ttl", "MAIL_VERIFICATION_TTL"),
+    ("mail.password_reset_ttl", "MAIL_PASSWORD_RESET_TTL"),
+    ("log.level", "LOG_LEVEL"),
+    ("log.format", "LOG_FORMAT"),
+    ("dev.docker_compose", "DEV_DOCKER_COMPOSE"),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigIssue {
+    pub key: String,
+    pub message: String,
+}
+
+impl ConfigIssue {
+    pub fn new(key: &str, message: &str) -> Self {
+        ConfigIssue {
+            key: key.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigError {
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Invalid configuration:")?;
+        for issue in &self.issues {
+            writeln!(f, "  {}: {}", issue.key, issue.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Raw configuration values keyed by dotted name. Each layer added later
+/// overrides the earlier ones: file, then environment, then command line.
+#[derive(Debug, Default)]
+pub struct ConfigSources {
+    values: BTreeMap<String, String>,
+    issues: Vec<ConfigIssue>,
+}
+
+impl ConfigSources {
+    /// Reads YAML from `.yaml`/`.yml` files and TOML from anything else.
+    pub fn with_file(self, path: &Path) -> Self {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) => {
+                return self.with_issue(
+                    "config",
+                    &format!("Cannot read {}: {}", path.display(), error),
+                )
+            }
+        };
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("yaml" | "yml") => self.with_yaml(&contents),
+            _ => self.with_toml(&contents),
+        }
+    }
+
+    pub fn with_toml(mut self, contents: &str) -> Self {
+        match contents.parse::<toml::Table>() {
+            Ok(table) => self.flatten("", &table),
+            Err(error) => {
+                return self.with_issue("config", &format!("Invalid TOML: {}", error.message()))
+            }
+        }
+        self
+    }
+
+    /// Read into the same table as TOML, so both formats accept the same keys
+    /// and value types.
+    pub fn with_yaml(mut self, contents: &str) -> Self {
+        if contents.trim().is_empty() {
+            return self;
+        }
+        match serde_yaml_ng::from_str::<toml::Table>(contents) {
+            Ok(table) => self.flatten("", &table),
+            Err(error) => return self.with_issue("config", &format!("Invalid YAML: {}", error)),
+        }
+        self
+    }
+
+    pub fn with_env(mut self) -> Self {
+        for (key, variable) in KEYS {
+            if let Ok(value) = env::var(variable) {
+                self.values.insert(key.to_string(), value);
+            }
+        }
+        self
+    }
+
+    pub fn with_overrides(mut self, overrides: &[(String, String)]) -> Self {
+        for (key, value) in overrides {
+            self.set(key, value.clone());
+        }
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    pub fn string_or(&self, key: &str, default: &str) -> String {
+        self.get(key).unwrap_or(default).to_string()
+    }
+
+    /// Parses `key`, recording an issue (and falling back to `default`) when it is malformed.
+    pub fn parse_or<T: FromStr>(
+        &self,
+        key: &str,
+        default: T,
+        expected: &str,
+        issues: &mut Vec<ConfigIssue>,
+    ) -> T {
+        match self.get(key).map(str::parse::<T>) {
+            None => default,
+            Some(Ok(value)) => value,
+            Some(Err(_)) => {
+                issues.push(ConfigIssue::new(key, &format!("Must be {}", expected)));
+                default
+            }
+        }
+    }
+
+    pub fn bool_or(&self, key: &str, default: bool, issues: &mut Vec<ConfigIssue>) -> bool {
+        match self.get(key) {
+            None => default,
+            Some("true" | "1") => true,
+            Some("false" | "0") => false,
+            Some(_) => {
+                issues.push(ConfigIssue::new(key, "Must be true or false"));
+                default
+            }
+        }
+    }
+
+    pub fn issues(&self) -> &[ConfigIssue] {
+        &self.issues
+    }
+
+    fn flatten(&mut self, prefix: &str, table: &toml::Table) {
+        for (name, value) in table {
+            let key = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{}.{}", prefix, name)
+            };
+            match value {
+                toml::Value::Table(table) => self.flatten(&key, table),
+                toml::Value::String(value) => self.set(&key, value.clone()),
+                toml::Value::Integer(value) => self.set(&key, value.to_string()),
+                toml::Value::Boolean(value) => self.set(&key, value.to_string()),
+                _ => self.issues.push(ConfigIssue::new(
+                    &key,
+                    "Must be a string, integer or boolean",
+                )),
+            }
+        }
+    }
+
+    fn set(&mut self, key: &str, value: String) {
+        if KEYS.iter().any(|(known, _)| *known == key) {
+            self.values.insert(key.to_string(), value);
+        } else {
+            self.issues.push(ConfigIssue::new(key, "Unknown key"));
+        }
+    }
+
+    fn with_issue(mut self, key: &str, message: &str) -> Self {
+        self.issues.push(ConfigIssue::new(key, message));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toml_tables_become_dotted_keys() {
+        let sources = ConfigSources::default()
+            .with_toml("environment = \"production\"\n[server]\nport = 9090\n");
+        assert_eq!(sources.get("environment"), Some("production"));
+        assert_eq!(sources.get("server.port"), Some("9090"));
+        assert!(sources.issues().is_empty());
+    }
+
+    #[test]
+    fn test_yaml_mappings_become_dotted_keys() {
+        let sources = ConfigSources::default()
+            .with_yaml("environment: production\nserver:\n  port: 9090\n  tls_cert: certs/a.pem\n");
+        assert_eq!(sources.get("environment"), Some("production"));
+        assert_eq!(sources.get("server.port"), Some("9090"));
+        assert_eq!(sources.get("server.tls_cert"), Some("certs/a.pem"));
+        assert!(sources.issues().is_empty());
+
+        let sources = ConfigSources::default().with_yaml("server: [9090]\n");
+        assert_eq!(sources.issues()[0].key, "server");
+        let sources = ConfigSources::default().with_yaml("server: [9090\n");
+        assert_eq!(sources.issues()[0].key, "config");
+    }
+
+    #[test]
+    fn test_file_extension_selects_the_format() {
+        let directory = env::temp_dir().join(format!("user-api-config-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let yaml = directory.join("config.yml");
+        fs::write(&yaml, "server:\n  port: 9090\n").unwrap();
+        let toml = directory.join("config.toml");
+        fs::write(&toml, "[server]\nport = 7070\n").unwrap();
+
+        let sources = ConfigSources::default().with_file(&yaml);
+        assert_eq!(sources.get("server.port"), Some("9090"));
+        assert!(sources.issues().is_empty());
+        let sources = ConfigSources::default().with_file(&toml);
+        assert_eq!(sources.get("server.port"), Some("7070"));
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_later_layers_override_earlier_ones() {
+        let sources = ConfigSources::default()
+            .with_toml("[server]\nport = 9090\n")
+            .with_overrides(&[("server.port".to_string(), "7070".to_string())]);
+        assert_eq!(sources.get("server.port"), Some("7070"));
+    }
+
+    #[test]
+    fn test_unknown_keys_are_reported() {
+        let sources = ConfigSources::default()
+            .with_toml("[server]\nprot = 9090\n")
+            .with_overrides(&[("database.hots".to_string(), "db".to_string())]);
+        assert_eq!(
+            sources.issues(),
+            &[
+                ConfigIssue::new("server.prot", "Unknown key"),
+                ConfigIssue::new("database.hots", "Unknown key"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use crate::core::domain::permission::Permission;
+use crate::core::domain::role::Role;
+
+/// The principal behind a request, as asserted by a verified bearer token.
+///
+/// `roles` are trusted from the token rather than read per request. Tokens this
+/// service issues copy them from `user_roles` at login and refresh, so a grant
+/// or revocation reaches a caller once their access token is next renewed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Caller {
+    pub subject: String,
+    pub roles: Vec<Role>,
+}
+
+impl Caller {
+    pub fn new(subject: String, roles: Vec<Role>) -> Self {
+        Caller { subject, roles }
+    }
+
+    /// The caller's own user id, when the token subject names a user.
+    pub fn user_id(&self) -> Option<i32> {
+        self.subject.parse().ok()
+    }
+
+    pub fn can(&self, permission: Permission) -> bool {
+        self.roles
+            .iter()
+            .any(|role| role.permissions().contains(&permission))
+    }
+}
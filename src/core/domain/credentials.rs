@@ -0,0 +1,64 @@
+use crate::core::domain::user::User;
+use std::fmt;
+
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+/// Argon2 accepts longer inputs; the cap only bounds the work per request.
+pub const MAX_PASSWORD_LENGTH: usize = 128;
+
+/// A user together with their stored password hash, for login checks only.
+#[derive(PartialEq)]
+pub struct UserCredentials {
+    pub user: User,
+    /// `None` for accounts created without a password; they cannot log in.
+    pub password_hash: Option<String>,
+}
+
+impl fmt::Debug for UserCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserCredentials")
+            .field("user", &self.user)
+            .field(
+                "password_hash",
+                &self.password_hash.as_ref().map(|_| "********"),
+            )
+            .finish()
+    }
+}
+
+pub fn validate_password(password: &str) -> Result<(), String> {
+    let length = password.chars().count();
+    if length < MIN_PASSWORD_LENGTH {
+        return Err(format!(
+            "Password must be at least {} characters",
+            MIN_PASSWORD_LENGTH
+        ));
+    }
+    if length > MAX_PASSWORD_LENGTH {
+        return Err(format!(
+            "Password must be at most {} characters",
+            MAX_PASSWORD_LENGTH
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_password_enforces_length() {
+        assert!(validate_password("short").is_err());
+        assert!(validate_password("long enough").is_ok());
+        assert!(validate_password(&"a".repeat(MAX_PASSWORD_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_debug_hides_the_hash() {
+        let credentials = UserCredentials {
+            user: User::new(1, "John".to_string(), "john@email.com".to_string()),
+            password_hash: Some("$argon2id$secret".to_string()),
+        };
+        assert!(!format!("{:?}", credentials).contains("secret"));
+    }
+}
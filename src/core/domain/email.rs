@@ -0,0 +1,243 @@
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// Matches the `users.email VARCHAR(255)` column.
+pub const MAX_EMAIL_LENGTH: usize = 255;
+const MAX_LOCAL_PART_LENGTH: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EmailError {
+    Empty,
+    TooLong,
+    InvalidCharacter,
+    MissingAtSign,
+    InvalidLocalPart,
+    LocalPartTooLong,
+    InvalidDomain,
+}
+
+impl fmt::Display for EmailError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            EmailError::Empty => "Email address is required".to_string(),
+            EmailError::TooLong => format!(
+                "Email address must be at most {} characters",
+                MAX_EMAIL_LENGTH
+            ),
+            EmailError::InvalidCharacter => {
+                "Email address must not contain whitespace or control characters".to_string()
+            }
+            EmailError::MissingAtSign => "Email address must contain an @".to_string(),
+            EmailError::InvalidLocalPart => "Email address has an invalid local part".to_string(),
+            EmailError::LocalPartTooLong => format!(
+                "Email local part must be at most {} characters",
+                MAX_LOCAL_PART_LENGTH
+            ),
+            EmailError::InvalidDomain => "Email address has an invalid domain".to_string(),
+        };
+        write!(f, "{}", message)
+    }
+}
+
+/// An address parsed per RFC 5322 `addr-spec` (without comments or folding whitespace).
+/// The local part is kept as written; the domain is IDNA-encoded and lowercased.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Email(String);
+
+impl Email {
+    pub fn parse(value: &str) -> Result<Self, EmailError> {
+        if value.is_empty() {
+            return Err(EmailError::Empty);
+        }
+        if value.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            return Err(EmailError::InvalidCharacter);
+        }
+
+        let (local_part, domain) = value.rsplit_once('@').ok_or(EmailError::MissingAtSign)?;
+        Self::validate_local_part(local_part)?;
+        let domain = Self::normalize_domain(domain)?;
+
+        let email = format!("{}@{}", local_part, domain);
+        if email.len() > MAX_EMAIL_LENGTH {
+            return Err(EmailError::TooLong);
+        }
+        Ok(Email(email))
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    fn validate_local_part(local_part: &str) -> Result<(), EmailError> {
+        if local_part.len() > MAX_LOCAL_PART_LENGTH {
+            return Err(EmailError::LocalPartTooLong);
+        }
+        let valid =
+            if local_part.len() >= 2 && local_part.starts_with('"') && local_part.ends_with('"') {
+                Self::is_quoted_string(&local_part[1..local_part.len() - 1])
+            } else {
+                Self::is_dot_atom(local_part)
+            };
+        if valid {
+            Ok(())
+        } else {
+            Err(EmailError::InvalidLocalPart)
+        }
+    }
+
+    fn is_dot_atom(value: &str) -> bool {
+        !value.is_empty()
+            && value.split('.').all(|atom| {
+                !atom.is_empty()
+                    && atom.chars().all(|c| {
+                        c.is_ascii_alphanumeric()
+                            || "!#$%&'*+-/=?^_`{|}~".contains(c)
+                            || !c.is_ascii()
+                    })
+            })
+    }
+
+    fn is_quoted_string(value: &str) -> bool {
+        let mut escaped = false;
+        for c in value.chars() {
+            if escaped {
+                escaped = false;
+                continue;
+            }
+            match c {
+                '\\' => escaped = true,
+                '"' => return false,
+                _ => {}
+            }
+        }
+        !escaped
+    }
+
+    fn normalize_domain(domain: &str) -> Result<String, EmailError> {
+        if let Some(literal) = domain.strip_prefix('[').and_then(|d| d.strip_suffix(']')) {
+            return Self::normalize_address_literal(literal).ok_or(EmailError::InvalidDomain);
+        }
+        if domain.is_empty() || domain.split('.').any(|label| label.is_empty()) {
+            return Err(EmailError::InvalidDomain);
+        }
+        idna::domain_to_ascii_strict(domain).map_err(|_| EmailError::InvalidDomain)
+    }
+
+    fn normalize_address_literal(literal: &str) -> Option<String> {
+        if let Some(address) = literal
+            .strip_prefix("IPv6:")
+            .or_else(|| literal.strip_prefix("ipv6:"))
+        {
+            return address
+                .parse::<Ipv6Addr>()
+                .ok()
+                .map(|address| format!("[IPv6:{}]", address));
+        }
+        literal
+            .parse::<Ipv4Addr>()
+            .ok()
+            .map(|address| format!("[{}]", address))
+    }
+}
+
+impl fmt::Display for Email {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_email() {
+        assert_eq!(
+            Email::parse("john@email.com").map(Email::into_string),
+            Ok("john@email.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_email_invalid_by_at_sign() {
+        assert_eq!(Email::parse("john.com"), Err(EmailError::MissingAtSign));
+    }
+
+    #[test]
+    fn test_parse_email_accepts_dotless_domain() {
+        assert!(Email::parse("a@b").is_ok());
+    }
+
+    #[test]
+    fn test_parse_email_rejects_empty_local_part() {
+        assert_eq!(Email::parse(".@"), Err(EmailError::InvalidLocalPart));
+        assert_eq!(
+            Email::parse("@email.com"),
+            Err(EmailError::InvalidLocalPart)
+        );
+    }
+
+    #[test]
+    fn test_parse_email_rejects_misplaced_dots() {
+        assert!(Email::parse("john..doe@email.com").is_err());
+        assert!(Email::parse("john.@email.com").is_err());
+        assert!(Email::parse("john@email..com").is_err());
+    }
+
+    #[test]
+    fn test_parse_email_rejects_whitespace_and_control_characters() {
+        assert_eq!(
+            Email::parse("john doe@email.com"),
+            Err(EmailError::InvalidCharacter)
+        );
+        assert_eq!(
+            Email::parse("john\u{7}@email.com"),
+            Err(EmailError::InvalidCharacter)
+        );
+    }
+
+    #[test]
+    fn test_parse_email_lowercases_domain_only() {
+        assert_eq!(
+            Email::parse("John.Doe@EMAIL.Com").map(Email::into_string),
+            Ok("John.Doe@email.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_email_encodes_international_domain() {
+        assert_eq!(
+            Email::parse("user@Bücher.example").map(Email::into_string),
+            Ok("user@xn--bcher-kva.example".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_email_accepts_quoted_local_part() {
+        assert!(Email::parse("\"john@home\"@email.com").is_ok());
+    }
+
+    #[test]
+    fn test_parse_email_accepts_address_literal() {
+        assert_eq!(
+            Email::parse("john@[127.0.0.1]").map(Email::into_string),
+            Ok("john@[127.0.0.1]".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_email_enforces_length_limits() {
+        let local_part = "a".repeat(MAX_LOCAL_PART_LENGTH + 1);
+        assert_eq!(
+            Email::parse(&format!("{}@email.com", local_part)),
+            Err(EmailError::LocalPartTooLong)
+        );
+
+        let domain = vec!["a".repeat(60); 4].join(".");
+        let local_part = "a".repeat(MAX_LOCAL_PART_LENGTH);
+        assert_eq!(
+            Email::parse(&format!("{}@{}", local_part, domain)),
+            Err(EmailError::TooLong)
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+
+/// A single-use link proving the holder reads `email`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailVerification {
+    pub token_hash: String,
+    pub user_id: i32,
+    pub email: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl EmailVerification {
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.used_at.is_none() && self.expires_at > now
+    }
+}
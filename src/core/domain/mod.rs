@@ -1 +1,12 @@
+pub mod caller;
+pub mod credentials;
+pub mod email;
+pub mod email_verification;
+pub mod password_reset;
+pub mod permission;
+pub mod role;
+pub mod secret_token;
+pub mod session;
 pub mod user;
+pub mod user_query;
+pub mod user_search;
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+
+/// A single-use permission to set a new password without knowing the old one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordReset {
+    pub token_hash: String,
+    pub user_id: i32,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl PasswordReset {
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.used_at.is_none() && self.expires_at > now
+    }
+}
@@ -0,0 +1,11 @@
+/// What a role allows; "own" permissions only apply to the caller's own record.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Permission {
+    CreateUsers,
+    ListUsers,
+    ReadAnyUser,
+    ReadOwnUser,
+    UpdateAnyUser,
+    UpdateOwnUser,
+    DeleteAnyUser,
+}
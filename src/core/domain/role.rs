@@ -0,0 +1,53 @@
+use crate::core::domain::permission::Permission;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Role {
+    Admin,
+    User,
+}
+
+impl Role {
+    /// Matches the `roles.name` values seeded by the roles migration.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "admin" => Some(Role::Admin),
+            "user" => Some(Role::User),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::User => "user",
+        }
+    }
+
+    pub fn permissions(&self) -> &'static [Permission] {
+        match self {
+            Role::Admin => &[
+                Permission::CreateUsers,
+                Permission::ListUsers,
+                Permission::ReadAnyUser,
+                Permission::ReadOwnUser,
+                Permission::UpdateAnyUser,
+                Permission::UpdateOwnUser,
+                Permission::DeleteAnyUser,
+            ],
+            Role::User => &[Permission::ReadOwnUser, Permission::UpdateOwnUser],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_round_trips() {
+        for role in [Role::Admin, Role::User] {
+            assert_eq!(Role::parse(role.as_str()), Some(role));
+        }
+        assert_eq!(Role::parse("root"), None);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::core::domain::secret_token::{generate_secret, hash_secret};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Matches the `sessions.user_agent VARCHAR(255)` column.
+pub const MAX_USER_AGENT_LENGTH: usize = 255;
+
+/// A login on one device, kept alive by rotating its refresh token.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub id: Uuid,
+    pub user_id: i32,
+    pub refresh_token_hash: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl Session {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+}
+
+/// What is known about the device opening a session.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionClient {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+impl SessionClient {
+    pub fn new(user_agent: Option<&str>, ip_address: Option<String>) -> Self {
+        SessionClient {
+            user_agent: user_agent.map(|agent| agent.chars().take(MAX_USER_AGENT_LENGTH).collect()),
+            ip_address,
+        }
+    }
+}
+
+/// The owner of a session together with its freshly issued refresh token.
+#[derive(Debug, PartialEq)]
+pub struct SessionGrant {
+    pub user_id: i32,
+    pub refresh_token: String,
+}
+
+/// `<session id>.<secret>`: the id locates the session, the secret proves
+/// possession and is only ever stored as a SHA-256 hash.
+#[derive(PartialEq)]
+pub struct RefreshToken {
+    pub session_id: Uuid,
+    secret: String,
+}
+
+impl RefreshToken {
+    pub fn generate(session_id: Uuid) -> Self {
+        RefreshToken {
+            session_id,
+            secret: generate_secret(),
+        }
+    }
+
+    pub fn parse(token: &str) -> Option<Self> {
+        let (session_id, secret) = token.split_once('.')?;
+        Some(RefreshToken {
+            session_id: Uuid::parse_str(session_id).ok()?,
+            secret: secret.to_string(),
+        })
+    }
+
+    pub fn hash(&self) -> String {
+        hash_secret(&self.secret)
+    }
+
+    pub fn matches(&self, session: &Session) -> bool {
+        self.session_id == session.id && self.hash() == session.refresh_token_hash
+    }
+}
+
+impl std::fmt::Display for RefreshToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.session_id, self.secret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refresh_tokens_round_trip() {
+        let token = RefreshToken::generate(Uuid::new_v4());
+        let parsed = RefreshToken::parse(&token.to_string()).unwrap();
+        assert!(parsed == token);
+        assert_eq!(parsed.hash(), token.hash());
+        assert_eq!(token.hash().len(), 64);
+        assert!(RefreshToken::parse("not-a-token").is_none());
+        assert!(RefreshToken::parse("1234.secret").is_none());
+    }
+
+    #[test]
+    fn test_generated_tokens_differ() {
+        let session_id = Uuid::new_v4();
+        assert!(
+            RefreshToken::generate(session_id).hash() != RefreshToken::generate(session_id).hash()
+        );
+    }
+
+    #[test]
+    fn test_user_agent_is_truncated_to_the_column() {
+        let client = SessionClient::new(Some(&"a".repeat(300)), None);
+        assert_eq!(client.user_agent.unwrap().len(), MAX_USER_AGENT_LENGTH);
+    }
+}
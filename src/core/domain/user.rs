@@ -1,8 +1,17 @@
+use crate::core::domain::role::Role;
+use chrono::{DateTime, Utc};
+
+/// Matches the `users.name VARCHAR(100)` column.
+pub const MAX_NAME_LENGTH: usize = 100;
+
 #[derive(Debug)]
 pub struct User {
     pub name: String,
     pub id: i32,
     pub email: String,
+    pub roles: Vec<Role>,
+    /// When the owner last proved control of `email`; cleared when it changes.
+    pub email_verified_at: Option<DateTime<Utc>>,
 }
 
 impl PartialEq for User {
@@ -13,33 +22,112 @@ impl PartialEq for User {
 
 impl User {
     pub fn new(id: i32, name: String, email: String) -> Self {
-        User { id, name, email }
+        User {
+            id,
+            name,
+            email,
+            roles: Vec::new(),
+            email_verified_at: None,
+        }
+    }
+
+    pub fn with_roles(mut self, roles: Vec<Role>) -> Self {
+        self.roles = roles;
+        self
+    }
+
+    pub fn with_email_verified_at(mut self, verified_at: Option<DateTime<Utc>>) -> Self {
+        self.email_verified_at = verified_at;
+        self
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct NewUser {
+    pub name: String,
+    pub email: String,
+    pub roles: Vec<Role>,
+    pub password_hash: Option<String>,
+}
+
+impl NewUser {
+    /// New accounts start as regular users.
+    pub fn new(name: String, email: String) -> Self {
+        NewUser {
+            name,
+            email,
+            roles: vec![Role::User],
+            password_hash: None,
+        }
     }
 
-    pub fn validate_email(&self) -> bool {
-        self.email.contains('@') && self.email.contains('.')
+    pub fn with_password_hash(mut self, password_hash: String) -> Self {
+        self.password_hash = Some(password_hash);
+        self
     }
 }
 
+#[derive(Debug, Default, PartialEq)]
+pub struct UserPatch {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+impl UserPatch {
+    pub fn new(name: Option<String>, email: Option<String>) -> Self {
+        UserPatch { name, email }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.email.is_none()
+    }
+}
+
+/// Checks a display name against the column limit; the name is stored as given.
+pub fn validate_name(name: &str) -> Result<(), String> {
+    if name.trim().is_empty() {
+        return Err("Name is required".to_string());
+    }
+    if name.chars().count() > MAX_NAME_LENGTH {
+        return Err(format!(
+            "Name must be at most {} characters",
+            MAX_NAME_LENGTH
+        ));
+    }
+    if name.chars().any(char::is_control) {
+        return Err("Name must not contain control characters".to_string());
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_validate_email() {
-        let user = User::new(1, "John".to_string(), "john@email.com".to_string());
-        assert!(user.validate_email());
+    fn test_validate_name_accepts_regular_names() {
+        assert!(validate_name("John Doe").is_ok());
+        assert!(validate_name(&"a".repeat(MAX_NAME_LENGTH)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_name_rejects_blank_names() {
+        assert!(validate_name("").is_err());
+        assert!(validate_name("   ").is_err());
+    }
+
+    #[test]
+    fn test_validate_name_rejects_names_longer_than_the_column() {
+        assert!(validate_name(&"a".repeat(MAX_NAME_LENGTH + 1)).is_err());
     }
 
     #[test]
-    fn test_validate_email_invalid_by_at_sign() {
-        let user = User::new(1, "John".to_string(), "john.com".to_string());
-        assert!(!user.validate_email());
+    fn test_validate_name_counts_characters_not_bytes() {
+        assert!(validate_name(&"é".repeat(MAX_NAME_LENGTH)).is_ok());
     }
 
     #[test]
-    fn test_validate_email_invalid_by_dot() {
-        let user = User::new(1, "John".to_string(), "john@com".to_string());
-        assert!(!user.validate_email());
+    fn test_validate_name_rejects_control_characters() {
+        assert!(validate_name("John\nDoe").is_err());
     }
 }
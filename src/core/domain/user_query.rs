@@ -0,0 +1,189 @@
+use crate::core::domain::user::User;
+use crate::core::errors::user_error::{FieldError, UserError};
+
+pub const DEFAULT_PAGE_SIZE: i64 = 50;
+pub const MAX_PAGE_SIZE: i64 = 100;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum UserSortField {
+    #[default]
+    Id,
+    Name,
+    Email,
+}
+
+impl UserSortField {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "id" => Some(UserSortField::Id),
+            "name" => Some(UserSortField::Name),
+            "email" => Some(UserSortField::Email),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "asc" => Some(SortDirection::Asc),
+            "desc" => Some(SortDirection::Desc),
+            _ => None,
+        }
+    }
+}
+
+/// Keyset pagination walks the id column, so it is only stable when sorting by id;
+/// offset pagination works with any sort order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserPagination {
+    Cursor { after: Option<i32>, limit: i64 },
+    Page { page: i64, per_page: i64 },
+}
+
+impl Default for UserPagination {
+    fn default() -> Self {
+        UserPagination::Cursor {
+            after: None,
+            limit: DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+/// Rows skipped before `page`; rejects pages below 1 and pages whose offset
+/// does not fit in an `i64`.
+pub fn page_offset(page: i64, per_page: i64) -> Result<i64, FieldError> {
+    if page < 1 {
+        return Err(FieldError::new("page", "Must be at least 1"));
+    }
+    (page - 1)
+        .checked_mul(per_page)
+        .ok_or_else(|| FieldError::new("page", "Is too large for the page size"))
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserQuery {
+    pub pagination: UserPagination,
+    pub sort: UserSortField,
+    pub direction: SortDirection,
+    pub email_domain: Option<String>,
+    pub name_contains: Option<String>,
+}
+
+impl UserQuery {
+    pub fn validate(&self) -> Result<(), UserError> {
+        let mut errors = Vec::new();
+        match self.pagination {
+            UserPagination::Cursor { after, limit } => {
+                if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+                    errors.push(FieldError::new(
+                        "limit",
+                        &format!("Must be between 1 and {}", MAX_PAGE_SIZE),
+                    ));
+                }
+                if after.is_some() && self.sort != UserSortField::Id {
+                    errors.push(FieldError::new(
+                        "cursor",
+                        "Cursor pagination requires sorting by id",
+                    ));
+                }
+            }
+            UserPagination::Page { page, per_page } => {
+                if let Err(error) = page_offset(page, per_page) {
+                    errors.push(error);
+                }
+                if !(1..=MAX_PAGE_SIZE).contains(&per_page) {
+                    errors.push(FieldError::new(
+                        "per_page",
+                        &format!("Must be between 1 and {}", MAX_PAGE_SIZE),
+                    ));
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(UserError::Validation(errors))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UserPage {
+    pub users: Vec<User>,
+    pub total: i64,
+    pub next_cursor: Option<i32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_query_is_valid() {
+        assert_eq!(UserQuery::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_limit_out_of_range_is_invalid() {
+        let query = UserQuery {
+            pagination: UserPagination::Cursor {
+                after: None,
+                limit: MAX_PAGE_SIZE + 1,
+            },
+            ..UserQuery::default()
+        };
+        assert!(query.validate().is_err());
+    }
+
+    #[test]
+    fn test_cursor_requires_id_sort() {
+        let query = UserQuery {
+            pagination: UserPagination::Cursor {
+                after: Some(1),
+                limit: 10,
+            },
+            sort: UserSortField::Name,
+            ..UserQuery::default()
+        };
+        assert!(query.validate().is_err());
+    }
+
+    #[test]
+    fn test_page_must_be_positive() {
+        let query = UserQuery {
+            pagination: UserPagination::Page {
+                page: 0,
+                per_page: 10,
+            },
+            ..UserQuery::default()
+        };
+        assert!(query.validate().is_err());
+    }
+
+    #[test]
+    fn test_page_offset_must_not_overflow() {
+        let query = UserQuery {
+            pagination: UserPagination::Page {
+                page: i64::MAX,
+                per_page: MAX_PAGE_SIZE,
+            },
+            ..UserQuery::default()
+        };
+        assert_eq!(
+            query.validate(),
+            Err(UserError::Validation(vec![FieldError::new(
+                "page",
+                "Is too large for the page size"
+            )]))
+        );
+        assert_eq!(page_offset(3, 10), Ok(20));
+    }
+}
@@ -0,0 +1,68 @@
+use crate::core::domain::user::User;
+use crate::core::errors::user_error::{FieldError, UserError};
+
+pub const DEFAULT_SEARCH_LIMIT: i64 = 20;
+pub const MAX_SEARCH_LIMIT: i64 = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserSearch {
+    pub term: String,
+    pub limit: i64,
+}
+
+impl UserSearch {
+    pub fn new(term: &str, limit: Option<i64>) -> Self {
+        UserSearch {
+            term: term.trim().to_string(),
+            limit: limit.unwrap_or(DEFAULT_SEARCH_LIMIT),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), UserError> {
+        let mut errors = Vec::new();
+        if self.term.is_empty() {
+            errors.push(FieldError::new("q", "Must not be empty"));
+        }
+        if self.term.chars().count() > 255 {
+            errors.push(FieldError::new("q", "Must be at most 255 characters"));
+        }
+        if !(1..=MAX_SEARCH_LIMIT).contains(&self.limit) {
+            errors.push(FieldError::new(
+                "limit",
+                &format!("Must be between 1 and {}", MAX_SEARCH_LIMIT),
+            ));
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(UserError::Validation(errors))
+        }
+    }
+}
+
+/// A search hit with its relevance in `0.0..=1.0`, higher meaning a closer match.
+#[derive(Debug, PartialEq)]
+pub struct UserMatch {
+    pub user: User,
+    pub score: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_term_is_trimmed() {
+        assert_eq!(UserSearch::new("  john ", None).term, "john");
+    }
+
+    #[test]
+    fn test_blank_search_is_invalid() {
+        assert!(UserSearch::new("   ", None).validate().is_err());
+    }
+
+    #[test]
+    fn test_search_limit_out_of_range_is_invalid() {
+        assert!(UserSearch::new("john", Some(0)).validate().is_err());
+    }
+}
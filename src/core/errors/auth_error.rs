@@ -0,0 +1,18 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthError {
+    MissingToken,
+    InvalidToken(String),
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthError::MissingToken => write!(f, "A bearer token is required"),
+            AuthError::InvalidToken(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {}
@@ -0,0 +1,2 @@
+pub mod auth_error;
+pub mod user_error;
@@ -0,0 +1,64 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &str, message: &str) -> Self {
+        FieldError {
+            field: field.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserError {
+    NotFound,
+    Conflict(String),
+    Validation(Vec<FieldError>),
+    Forbidden(String),
+    InvalidCredentials,
+    InvalidRefreshToken,
+    SessionNotFound,
+    InvalidVerificationToken,
+    InvalidResetToken,
+    Storage(String),
+    Unavailable(String),
+}
+
+impl UserError {
+    pub fn email_taken() -> Self {
+        UserError::Conflict("Email already in use".to_string())
+    }
+}
+
+impl fmt::Display for UserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UserError::NotFound => write!(f, "User not found"),
+            UserError::Conflict(message) => write!(f, "{}", message),
+            UserError::Validation(errors) => {
+                let details = errors
+                    .iter()
+                    .map(|error| format!("{}: {}", error.field, error.message))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                write!(f, "Validation failed: {}", details)
+            }
+            UserError::Forbidden(reason) => write!(f, "Forbidden: {}", reason),
+            UserError::InvalidCredentials => write!(f, "Invalid email or password"),
+            UserError::InvalidRefreshToken => write!(f, "Invalid refresh token"),
+            UserError::SessionNotFound => write!(f, "Session not found"),
+            UserError::InvalidVerificationToken => write!(f, "Invalid verification token"),
+            UserError::InvalidResetToken => write!(f, "Invalid password reset token"),
+            UserError::Storage(message) => write!(f, "Storage error: {}", message),
+            UserError::Unavailable(message) => write!(f, "Service unavailable: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for UserError {}
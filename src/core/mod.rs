@@ -1,3 +1,5 @@
 pub mod domain;
+pub mod errors;
+pub mod policies;
 pub mod repositories;
 pub mod services;
@@ -0,0 +1,144 @@
+use crate::core::domain::caller::Caller;
+use crate::core::domain::permission::Permission;
+use crate::core::errors::user_error::UserError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UserAction {
+    Create,
+    List,
+    Search,
+    Read(i32),
+    Update(i32),
+    Delete(i32),
+    ChangePassword(i32),
+    ListSessions(i32),
+    RevokeSessions(i32),
+    RequestEmailVerification(i32),
+}
+
+/// Decides whether the caller may perform `action`, explaining a denial.
+pub fn authorize(caller: &Caller, action: UserAction) -> Result<(), UserError> {
+    let is_own = |id: i32| caller.user_id() == Some(id);
+    let (allowed, reason) = match action {
+        UserAction::Create => (
+            caller.can(Permission::CreateUsers),
+            "Only admins can create users",
+        ),
+        UserAction::List | UserAction::Search => (
+            caller.can(Permission::ListUsers),
+            "Only admins can list users",
+        ),
+        UserAction::Read(id) => (
+            caller.can(Permission::ReadAnyUser)
+                || (is_own(id) && caller.can(Permission::ReadOwnUser)),
+            "Users can only read their own record",
+        ),
+        UserAction::Update(id) => (
+            caller.can(Permission::UpdateAnyUser)
+                || (is_own(id) && caller.can(Permission::UpdateOwnUser)),
+            "Users can only edit their own record",
+        ),
+        // Admins reset passwords elsewhere; knowing the old one is the caller's proof.
+        UserAction::ChangePassword(id) => (
+            is_own(id) && caller.can(Permission::UpdateOwnUser),
+            "Users can only change their own password",
+        ),
+        UserAction::ListSessions(id) => (
+            caller.can(Permission::ReadAnyUser)
+                || (is_own(id) && caller.can(Permission::ReadOwnUser)),
+            "Users can only see their own sessions",
+        ),
+        UserAction::RevokeSessions(id) => (
+            caller.can(Permission::UpdateAnyUser)
+                || (is_own(id) && caller.can(Permission::UpdateOwnUser)),
+            "Users can only revoke their own sessions",
+        ),
+        UserAction::RequestEmailVerification(id) => (
+            caller.can(Permission::UpdateAnyUser)
+                || (is_own(id) && caller.can(Permission::UpdateOwnUser)),
+            "Users can only verify their own email",
+        ),
+        UserAction::Delete(_) => (
+            caller.can(Permission::DeleteAnyUser),
+            "Only admins can delete users",
+        ),
+    };
+    if allowed {
+        Ok(())
+    } else {
+        Err(UserError::Forbidden(reason.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::role::Role;
+
+    fn admin() -> Caller {
+        Caller::new("1".to_string(), vec![Role::Admin])
+    }
+
+    fn user(id: i32) -> Caller {
+        Caller::new(id.to_string(), vec![Role::User])
+    }
+
+    #[test]
+    fn test_admins_can_do_anything() {
+        for action in [
+            UserAction::Create,
+            UserAction::List,
+            UserAction::Search,
+            UserAction::Read(7),
+            UserAction::Update(7),
+            UserAction::Delete(7),
+            UserAction::ListSessions(7),
+            UserAction::RevokeSessions(7),
+            UserAction::RequestEmailVerification(7),
+        ] {
+            assert_eq!(authorize(&admin(), action), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_users_can_read_and_edit_their_own_record() {
+        assert_eq!(authorize(&user(7), UserAction::Read(7)), Ok(()));
+        assert_eq!(authorize(&user(7), UserAction::Update(7)), Ok(()));
+        assert_eq!(authorize(&user(7), UserAction::ListSessions(7)), Ok(()));
+        assert_eq!(authorize(&user(7), UserAction::RevokeSessions(7)), Ok(()));
+    }
+
+    #[test]
+    fn test_users_cannot_touch_other_records() {
+        assert_eq!(
+            authorize(&user(7), UserAction::Read(8)),
+            Err(UserError::Forbidden(
+                "Users can only read their own record".to_string()
+            ))
+        );
+        assert!(authorize(&user(7), UserAction::Update(8)).is_err());
+        assert!(authorize(&user(7), UserAction::Delete(7)).is_err());
+        assert!(authorize(&user(7), UserAction::List).is_err());
+        assert!(authorize(&user(7), UserAction::Create).is_err());
+        assert!(authorize(&user(7), UserAction::ListSessions(8)).is_err());
+        assert!(authorize(&user(7), UserAction::RevokeSessions(8)).is_err());
+        assert!(authorize(&user(7), UserAction::RequestEmailVerification(8)).is_err());
+    }
+
+    #[test]
+    fn test_only_the_owner_can_change_a_password() {
+        assert_eq!(authorize(&user(7), UserAction::ChangePassword(7)), Ok(()));
+        assert_eq!(
+            authorize(&admin(), UserAction::ChangePassword(7)),
+            Err(UserError::Forbidden(
+                "Users can only change their own password".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_callers_without_roles_are_denied() {
+        let caller = Caller::new("7".to_string(), Vec::new());
+        assert!(authorize(&caller, UserAction::Read(7)).is_err());
+    }
+}
@@ -0,0 +1,16 @@
+use crate::core::domain::email_verification::EmailVerification;
+use crate::core::errors::user_error::UserError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait EmailVerificationRepository: Send + Sync {
+    async fn save_verification(&self, verification: &EmailVerification) -> Result<(), UserError>;
+    /// Marks the token used if it is still unused and unexpired at `now`,
+    /// returning it; `None` for unknown, used or expired tokens.
+    async fn use_verification(
+        &self,
+        token_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<EmailVerification>, UserError>;
+}
@@ -1 +1,4 @@
+pub mod email_verification_repository;
+pub mod password_reset_repository;
+pub mod session_repository;
 pub mod user_repository;
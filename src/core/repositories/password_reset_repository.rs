@@ -0,0 +1,16 @@
+use crate::core::domain::password_reset::PasswordReset;
+use crate::core::errors::user_error::UserError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait PasswordResetRepository: Send + Sync {
+    async fn save_reset(&self, reset: &PasswordReset) -> Result<(), UserError>;
+    /// Marks the token used if it is still unused and unexpired at `now`, and
+    /// voids the user's other outstanding tokens; `None` when it is not usable.
+    async fn use_reset(
+        &self,
+        token_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<PasswordReset>, UserError>;
+}
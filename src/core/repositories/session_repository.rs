@@ -0,0 +1,39 @@
+use crate::core::domain::session::Session;
+use crate::core::errors::user_error::UserError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    async fn save_session(&self, session: &Session) -> Result<(), UserError>;
+    async fn find_session(&self, id: Uuid) -> Result<Option<Session>, UserError>;
+    /// Sessions that are neither revoked nor expired at `now`, newest first.
+    async fn list_active_sessions(
+        &self,
+        user_id: i32,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Session>, UserError>;
+    /// Swaps the refresh token hash only if it is still `current_hash` and the
+    /// session is not revoked; `false` means another refresh won the race.
+    async fn rotate_refresh_token(
+        &self,
+        id: Uuid,
+        current_hash: &str,
+        new_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<bool, UserError>;
+    /// `false` when the user has no such unrevoked session.
+    async fn revoke_session(
+        &self,
+        user_id: i32,
+        id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<bool, UserError>;
+    /// Returns how many sessions were revoked.
+    async fn revoke_user_sessions(
+        &self,
+        user_id: i32,
+        now: DateTime<Utc>,
+    ) -> Result<usize, UserError>;
+}
@@ -1,14 +1,41 @@
-use crate::core::domain::user::User;
+use crate::core::domain::credentials::UserCredentials;
+use crate::core::domain::role::Role;
+use crate::core::domain::user::{NewUser, User, UserPatch};
+use crate::core::domain::user_query::{UserPage, UserQuery};
+use crate::core::domain::user_search::{UserMatch, UserSearch};
+use crate::core::errors::user_error::UserError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
-pub trait UserRepository {
-    fn save_user(&self, user: &User) -> Result<User, String>;
-    fn get_user_by_id(&self, id: i32) -> Option<User>;
-    fn delete_user(&self, id: i32) -> Result<User, String>;
-    fn list_users(&self) -> Vec<User>;
-    fn get_last_user(&self) -> Option<User>;
+#[async_trait]
+pub trait UserRepository: Send + Sync {
+    async fn save_user(&self, user: &NewUser) -> Result<User, UserError>;
+    async fn get_user_by_id(&self, id: i32) -> Result<User, UserError>;
+    async fn delete_user(&self, id: i32) -> Result<User, UserError>;
+    async fn list_users(&self, query: &UserQuery) -> Result<UserPage, UserError>;
+    async fn search(&self, search: &UserSearch) -> Result<Vec<UserMatch>, UserError>;
 
-    fn update_user(&self, user: &User) -> Result<User, String>;
+    async fn update_user(&self, user: &User) -> Result<User, UserError>;
+    async fn patch_user(&self, id: i32, patch: &UserPatch) -> Result<User, UserError>;
+    /// Adds `role` to the user's grants; granting one it already has is a no-op.
+    async fn grant_role(&self, id: i32, role: Role) -> Result<User, UserError>;
+
+    /// Looks the email up case-insensitively; `None` when no account uses it.
+    async fn find_credentials_by_email(
+        &self,
+        email: &str,
+    ) -> Result<Option<UserCredentials>, UserError>;
+    async fn get_password_hash(&self, id: i32) -> Result<Option<String>, UserError>;
+    async fn set_password_hash(&self, id: i32, password_hash: &str) -> Result<(), UserError>;
+    /// Marks the email verified only while the user still has `email`;
+    /// returns `false` when it has changed since the link was sent.
+    async fn mark_email_verified(
+        &self,
+        id: i32,
+        email: &str,
+        verified_at: DateTime<Utc>,
+    ) -> Result<bool, UserError>;
 
     #[cfg(test)]
-    fn drop_database(&self) -> Result<(), String>;
+    async fn drop_database(&self) -> Result<(), UserError>;
 }
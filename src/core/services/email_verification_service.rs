@@ -0,0 +1,10 @@
+use crate::core::domain::user::User;
+use crate::core::errors::user_error::UserError;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait EmailVerificationService: Send + Sync {
+    /// Emails the user a single-use link that proves they own the address.
+    async fn send_verification(&self, user_id: i32) -> Result<(), UserError>;
+    async fn verify(&self, token: &str) -> Result<User, UserError>;
+}
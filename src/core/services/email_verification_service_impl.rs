@@ -0,0 +1,176 @@
+use crate::core::domain::email_verification::EmailVerification;
+use crate::core::domain::secret_token::{generate_secret, hash_secret};
+use crate::core::domain::user::User;
+use crate::core::errors::user_error::UserError;
+use crate::core::repositories::email_verification_repository::EmailVerificationRepository;
+use crate::core::repositories::user_repository::UserRepository;
+use crate::core::services::email_verification_service::EmailVerificationService;
+use crate::core::services::mailer::{MailMessage, Mailer};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub struct EmailVerificationServiceImpl {
+    users: Box<dyn UserRepository>,
+    verifications: Box<dyn EmailVerificationRepository>,
+    mailer: Arc<dyn Mailer>,
+    /// Base URL the `/verify` link is built on.
+    public_url: String,
+    ttl: Duration,
+}
+
+impl EmailVerificationServiceImpl {
+    pub fn new(
+        users: Box<dyn UserRepository>,
+        verifications: Box<dyn EmailVerificationRepository>,
+        mailer: Arc<dyn Mailer>,
+        public_url: &str,
+        ttl: Duration,
+    ) -> Self {
+        EmailVerificationServiceImpl {
+            users,
+            verifications,
+            mailer,
+            public_url: public_url.to_string(),
+            ttl,
+        }
+    }
+
+    fn message(&self, user: &User, token: &str) -> MailMessage {
+        let body = format!(
+            "Hi {},\n\n\
+             Confirm that {} is your email address by opening this link within {} hours:\n\n\
+             {}/verify?token={}\n\n\
+             If you did not expect this email, you can ignore it.\n",
+            user.name,
+            user.email,
+            self.ttl.as_secs().div_ceil(3600),
+            self.public_url,
+            token
+        );
+        MailMessage {
+            to: user.email.clone(),
+            subject: "Verify your email address".to_string(),
+            body,
+        }
+    }
+}
+
+#[async_trait]
+impl EmailVerificationService for EmailVerificationServiceImpl {
+    async fn send_verification(&self, user_id: i32) -> Result<(), UserError> {
+        let user = self.users.get_user_by_id(user_id).await?;
+        if user.email_verified_at.is_some() {
+            return Err(UserError::Conflict("Email is already verified".to_string()));
+        }
+        let now = Utc::now();
+        let ttl = chrono::Duration::from_std(self.ttl).map_err(|error| {
+            UserError::Storage(format!("Invalid verification lifetime: {}", error))
+        })?;
+        let token = generate_secret();
+        let verification = EmailVerification {
+            token_hash: hash_secret(&token),
+            user_id: user.id,
+            email: user.email.clone(),
+            created_at: now,
+            expires_at: now + ttl,
+            used_at: None,
+        };
+        self.verifications.save_verification(&verification).await?;
+        self.mailer.send(&self.message(&user, &token)).await
+    }
+
+    async fn verify(&self, token: &str) -> Result<User, UserError> {
+        let now = Utc::now();
+        let verification = self
+            .verifications
+            .use_verification(&hash_secret(token), now)
+            .await?
+            .ok_or(UserError::InvalidVerificationToken)?;
+        match self
+            .users
+            .mark_email_verified(verification.user_id, &verification.email, now)
+            .await
+        {
+            Ok(true) => self.users.get_user_by_id(verification.user_id).await,
+            // The email changed after the link was sent.
+            Ok(false) | Err(UserError::NotFound) => Err(UserError::InvalidVerificationToken),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::user::{NewUser, UserPatch};
+    use crate::infrastructure::database::memory::memory_database::MemoryDatabase;
+    use crate::infrastructure::database::memory::memory_email_verification_repository::MemoryEmailVerificationRepository;
+    use crate::infrastructure::database::memory::memory_user_repository::MemoryUserRepository;
+    use crate::infrastructure::mail::outbox_mailer::OutboxMailer;
+
+    fn service(
+        database: &MemoryDatabase,
+        outbox: Arc<OutboxMailer>,
+    ) -> EmailVerificationServiceImpl {
+        EmailVerificationServiceImpl::new(
+            Box::new(MemoryUserRepository::new(database.clone())),
+            Box::new(MemoryEmailVerificationRepository::new(database.clone())),
+            outbox,
+            "http://localhost:8080",
+            Duration::from_secs(3600),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_links_verify_once() {
+        let database = MemoryDatabase::new();
+        let outbox = Arc::new(OutboxMailer::in_memory("no-reply@localhost"));
+        let service = service(&database, outbox.clone());
+        let user = MemoryUserRepository::new(database)
+            .save_user(&NewUser::new(
+                "John".to_string(),
+                "john@email.com".to_string(),
+            ))
+            .await
+            .unwrap();
+        service.send_verification(user.id).await.unwrap();
+        let token = outbox.last_token().unwrap();
+
+        let verified = service.verify(&token).await.unwrap();
+        assert!(verified.email_verified_at.is_some());
+        assert_eq!(
+            service.verify(&token).await,
+            Err(UserError::InvalidVerificationToken)
+        );
+        assert_eq!(
+            service.send_verification(user.id).await,
+            Err(UserError::Conflict("Email is already verified".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_links_for_a_previous_email_are_rejected() {
+        let database = MemoryDatabase::new();
+        let outbox = Arc::new(OutboxMailer::in_memory("no-reply@localhost"));
+        let service = service(&database, outbox.clone());
+        let users = MemoryUserRepository::new(database);
+        let user = users
+            .save_user(&NewUser::new(
+                "John".to_string(),
+                "john@email.com".to_string(),
+            ))
+            .await
+            .unwrap();
+        service.send_verification(user.id).await.unwrap();
+        let token = outbox.last_token().unwrap();
+        let patch = UserPatch::new(None, Some("john.doe@email.com".to_string()));
+        users.patch_user(user.id, &patch).await.unwrap();
+
+        assert_eq!(
+            service.verify(&token).await,
+            Err(UserError::InvalidVerificationToken)
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use crate::core::errors::user_error::UserError;
+use async_trait::async_trait;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MailMessage {
+    pub to: String,
+    pub subject: String,
+    /// Plain text.
+    pub body: String,
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, message: &MailMessage) -> Result<(), UserError>;
+}
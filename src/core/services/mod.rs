@@ -1,2 +1,11 @@
+pub mod email_verification_service;
+pub mod email_verification_service_impl;
+pub mod mailer;
+pub mod password_hasher;
+pub mod password_reset_service;
+pub mod password_reset_service_impl;
+pub mod session_service;
+pub mod session_service_impl;
+pub mod token_issuer;
 pub mod user_service;
 pub mod user_service_impl;
@@ -0,0 +1,10 @@
+use crate::core::errors::user_error::UserError;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait PasswordHasher: Send + Sync {
+    async fn hash(&self, password: &str) -> Result<String, UserError>;
+    /// Checks `password` against `hash`. Without a hash the check still costs
+    /// as much as a real one, so timing does not reveal unknown accounts.
+    async fn verify(&self, password: &str, hash: Option<&str>) -> bool;
+}
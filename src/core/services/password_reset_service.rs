@@ -0,0 +1,11 @@
+use crate::core::errors::user_error::UserError;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait PasswordResetService: Send + Sync {
+    /// Emails a single-use reset link if an account uses `email`; an unknown
+    /// email is not an error, so the result never reveals which ones exist.
+    async fn request_reset(&self, email: &str) -> Result<(), UserError>;
+    /// Sets the new password and signs the user out of every session.
+    async fn reset_password(&self, token: &str, new_password: &str) -> Result<(), UserError>;
+}
@@ -0,0 +1,203 @@
+use crate::core::domain::credentials::validate_password;
+use crate::core::domain::password_reset::PasswordReset;
+use crate::core::domain::secret_token::{generate_secret, hash_secret};
+use crate::core::domain::user::User;
+use crate::core::errors::user_error::{FieldError, UserError};
+use crate::core::repositories::password_reset_repository::PasswordResetRepository;
+use crate::core::repositories::session_repository::SessionRepository;
+use crate::core::repositories::user_repository::UserRepository;
+use crate::core::services::mailer::{MailMessage, Mailer};
+use crate::core::services::password_hasher::PasswordHasher;
+use crate::core::services::password_reset_service::PasswordResetService;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
+
+pub struct PasswordResetServiceImpl {
+    users: Box<dyn UserRepository>,
+    resets: Box<dyn PasswordResetRepository>,
+    sessions: Box<dyn SessionRepository>,
+    hasher: Box<dyn PasswordHasher>,
+    mailer: Arc<dyn Mailer>,
+    /// Base URL the `/reset-password` link is built on.
+    public_url: String,
+    ttl: Duration,
+}
+
+impl PasswordResetServiceImpl {
+    pub fn new(
+        users: Box<dyn UserRepository>,
+        resets: Box<dyn PasswordResetRepository>,
+        sessions: Box<dyn SessionRepository>,
+        hasher: Box<dyn PasswordHasher>,
+        mailer: Arc<dyn Mailer>,
+        public_url: &str,
+        ttl: Duration,
+    ) -> Self {
+        PasswordResetServiceImpl {
+            users,
+            resets,
+            sessions,
+            hasher,
+            mailer,
+            public_url: public_url.to_string(),
+            ttl,
+        }
+    }
+
+    fn message(&self, user: &User, token: &str) -> MailMessage {
+        let body = format!(
+            "Hi {},\n\n\
+             Choose a new password by opening this link within {} minutes:\n\n\
+             {}/reset-password?token={}\n\n\
+             Resetting your password signs you out everywhere. \
+             If you did not ask for this, you can ignore this email.\n",
+            user.name,
+            self.ttl.as_secs().div_ceil(60),
+            self.public_url,
+            token
+        );
+        MailMessage {
+            to: user.email.clone(),
+            subject: "Reset your password".to_string(),
+            body,
+        }
+    }
+}
+
+#[async_trait]
+impl PasswordResetService for PasswordResetServiceImpl {
+    async fn request_reset(&self, email: &str) -> Result<(), UserError> {
+        let Some(credentials) = self.users.find_credentials_by_email(email).await? else {
+            info!("Password reset requested for an unknown email");
+            return Ok(());
+        };
+        let user = credentials.user;
+        let now = Utc::now();
+        let ttl = chrono::Duration::from_std(self.ttl).map_err(|error| {
+            UserError::Storage(format!("Invalid password reset lifetime: {}", error))
+        })?;
+        let token = generate_secret();
+        let reset = PasswordReset {
+            token_hash: hash_secret(&token),
+            user_id: user.id,
+            created_at: now,
+            expires_at: now + ttl,
+            used_at: None,
+        };
+        self.resets.save_reset(&reset).await?;
+        self.mailer.send(&self.message(&user, &token)).await
+    }
+
+    async fn reset_password(&self, token: &str, new_password: &str) -> Result<(), UserError> {
+        if let Err(message) = validate_password(new_password) {
+            return Err(UserError::Validation(vec![FieldError::new(
+                "new_password",
+                &message,
+            )]));
+        }
+        let now = Utc::now();
+        let reset = self
+            .resets
+            .use_reset(&hash_secret(token), now)
+            .await?
+            .ok_or(UserError::InvalidResetToken)?;
+        let hash = self.hasher.hash(new_password).await?;
+        match self.users.set_password_hash(reset.user_id, &hash).await {
+            Err(UserError::NotFound) => return Err(UserError::InvalidResetToken),
+            result => result?,
+        }
+        let revoked = self
+            .sessions
+            .revoke_user_sessions(reset.user_id, now)
+            .await?;
+        info!(user_id = reset.user_id, revoked, "Password reset");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::credentials_config::CredentialsConfig;
+    use crate::core::domain::session::Session;
+    use crate::core::domain::user::NewUser;
+    use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
+    use crate::infrastructure::database::memory::memory_database::MemoryDatabase;
+    use crate::infrastructure::database::memory::memory_password_reset_repository::MemoryPasswordResetRepository;
+    use crate::infrastructure::database::memory::memory_session_repository::MemorySessionRepository;
+    use crate::infrastructure::database::memory::memory_user_repository::MemoryUserRepository;
+    use crate::infrastructure::mail::outbox_mailer::OutboxMailer;
+
+    fn service(database: &MemoryDatabase, outbox: Arc<OutboxMailer>) -> PasswordResetServiceImpl {
+        PasswordResetServiceImpl::new(
+            Box::new(MemoryUserRepository::new(database.clone())),
+            Box::new(MemoryPasswordResetRepository::new(database.clone())),
+            Box::new(MemorySessionRepository::new(database.clone())),
+            Box::new(Argon2PasswordHasher::new(&CredentialsConfig {
+                memory_kib: 1024,
+                iterations: 1,
+                parallelism: 1,
+            })),
+            outbox,
+            "http://localhost:8080",
+            Duration::from_secs(3600),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_unknown_emails_are_accepted_silently() {
+        let outbox = Arc::new(OutboxMailer::in_memory("no-reply@localhost"));
+        let service = service(&MemoryDatabase::new(), outbox.clone());
+        assert_eq!(service.request_reset("nobody@email.com").await, Ok(()));
+        assert!(outbox.messages().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reset_sets_the_password_once_and_revokes_sessions() {
+        let database = MemoryDatabase::new();
+        let outbox = Arc::new(OutboxMailer::in_memory("no-reply@localhost"));
+        let service = service(&database, outbox.clone());
+        let users = MemoryUserRepository::new(database.clone());
+        let sessions = MemorySessionRepository::new(database);
+        let user = users
+            .save_user(&NewUser::new(
+                "John".to_string(),
+                "john@email.com".to_string(),
+            ))
+            .await
+            .unwrap();
+        let now = Utc::now();
+        let session = Session {
+            id: uuid::Uuid::new_v4(),
+            user_id: user.id,
+            refresh_token_hash: "hash".to_string(),
+            user_agent: None,
+            ip_address: None,
+            created_at: now,
+            last_used_at: now,
+            expires_at: now + chrono::Duration::hours(1),
+            revoked_at: None,
+        };
+        sessions.save_session(&session).await.unwrap();
+        service.request_reset("JOHN@email.com").await.unwrap();
+        let token = outbox.last_token().unwrap();
+
+        service
+            .reset_password(&token, "new-password")
+            .await
+            .unwrap();
+        let hash = users.get_password_hash(user.id).await.unwrap();
+        assert!(hash.is_some());
+        assert_eq!(
+            sessions.list_active_sessions(user.id, Utc::now()).await,
+            Ok(Vec::new())
+        );
+        assert_eq!(
+            service.reset_password(&token, "other-password").await,
+            Err(UserError::InvalidResetToken)
+        );
+    }
+}
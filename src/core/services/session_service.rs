@@ -0,0 +1,21 @@
+use crate::core::domain::session::{Session, SessionClient, SessionGrant};
+use crate::core::errors::user_error::UserError;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait SessionService: Send + Sync {
+    async fn open_session(
+        &self,
+        user_id: i32,
+        client: SessionClient,
+    ) -> Result<SessionGrant, UserError>;
+    /// Exchanges a refresh token for its successor. Presenting a token that was
+    /// already exchanged revokes the whole session.
+    async fn refresh(&self, refresh_token: &str) -> Result<SessionGrant, UserError>;
+    /// Revokes the session behind a refresh token; unknown tokens are ignored.
+    async fn close_session(&self, refresh_token: &str) -> Result<(), UserError>;
+    async fn list_sessions(&self, user_id: i32) -> Result<Vec<Session>, UserError>;
+    async fn revoke_session(&self, user_id: i32, session_id: Uuid) -> Result<(), UserError>;
+    async fn revoke_all_sessions(&self, user_id: i32) -> Result<usize, UserError>;
+}
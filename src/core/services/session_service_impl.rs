@@ -0,0 +1,201 @@
+use crate::core::domain::session::{RefreshToken, Session, SessionClient, SessionGrant};
+use crate::core::errors::user_error::UserError;
+use crate::core::repositories::session_repository::SessionRepository;
+use crate::core::services::session_service::SessionService;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::time::Duration;
+use tracing::warn;
+use uuid::Uuid;
+
+pub struct SessionServiceImpl {
+    repository: Box<dyn SessionRepository>,
+    ttl: Duration,
+}
+
+impl SessionServiceImpl {
+    pub fn new(repository: Box<dyn SessionRepository>, ttl: Duration) -> Self {
+        SessionServiceImpl { repository, ttl }
+    }
+
+    /// A stale token means it leaked or was replayed; neither holder can be
+    /// trusted, so the session ends and the owner has to log in again.
+    async fn revoke_after_reuse(&self, session: &Session) -> Result<(), UserError> {
+        warn!(
+            session_id = %session.id,
+            user_id = session.user_id,
+            "Refresh token reuse detected, revoking the session"
+        );
+        self.repository
+            .revoke_session(session.user_id, session.id, Utc::now())
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SessionService for SessionServiceImpl {
+    async fn open_session(
+        &self,
+        user_id: i32,
+        client: SessionClient,
+    ) -> Result<SessionGrant, UserError> {
+        let now = Utc::now();
+        let ttl = chrono::Duration::from_std(self.ttl)
+            .map_err(|error| UserError::Storage(format!("Invalid session lifetime: {}", error)))?;
+        let token = RefreshToken::generate(Uuid::new_v4());
+        let session = Session {
+            id: token.session_id,
+            user_id,
+            refresh_token_hash: token.hash(),
+            user_agent: client.user_agent,
+            ip_address: client.ip_address,
+            created_at: now,
+            last_used_at: now,
+            expires_at: now + ttl,
+            revoked_at: None,
+        };
+        self.repository.save_session(&session).await?;
+        Ok(SessionGrant {
+            user_id,
+            refresh_token: token.to_string(),
+        })
+    }
+
+    async fn refresh(&self, refresh_token: &str) -> Result<SessionGrant, UserError> {
+        let token = RefreshToken::parse(refresh_token).ok_or(UserError::InvalidRefreshToken)?;
+        let session = self
+            .repository
+            .find_session(token.session_id)
+            .await?
+            .filter(|session| session.is_active(Utc::now()))
+            .ok_or(UserError::InvalidRefreshToken)?;
+        if !token.matches(&session) {
+            self.revoke_after_reuse(&session).await?;
+            return Err(UserError::InvalidRefreshToken);
+        }
+
+        let next = RefreshToken::generate(session.id);
+        let rotated = self
+            .repository
+            .rotate_refresh_token(
+                session.id,
+                &session.refresh_token_hash,
+                &next.hash(),
+                Utc::now(),
+            )
+            .await?;
+        if !rotated {
+            // Someone exchanged the same token in the meantime.
+            self.revoke_after_reuse(&session).await?;
+            return Err(UserError::InvalidRefreshToken);
+        }
+        Ok(SessionGrant {
+            user_id: session.user_id,
+            refresh_token: next.to_string(),
+        })
+    }
+
+    async fn close_session(&self, refresh_token: &str) -> Result<(), UserError> {
+        let Some(token) = RefreshToken::parse(refresh_token) else {
+            return Ok(());
+        };
+        if let Some(session) = self.repository.find_session(token.session_id).await? {
+            if token.matches(&session) {
+                self.repository
+                    .revoke_session(session.user_id, session.id, Utc::now())
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn list_sessions(&self, user_id: i32) -> Result<Vec<Session>, UserError> {
+        self.repository
+            .list_active_sessions(user_id, Utc::now())
+            .await
+    }
+
+    async fn revoke_session(&self, user_id: i32, session_id: Uuid) -> Result<(), UserError> {
+        match self
+            .repository
+            .revoke_session(user_id, session_id, Utc::now())
+            .await?
+        {
+            true => Ok(()),
+            false => Err(UserError::SessionNotFound),
+        }
+    }
+
+    async fn revoke_all_sessions(&self, user_id: i32) -> Result<usize, UserError> {
+        self.repository
+            .revoke_user_sessions(user_id, Utc::now())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::database::memory::memory_database::MemoryDatabase;
+    use crate::infrastructure::database::memory::memory_session_repository::MemorySessionRepository;
+
+    fn service() -> SessionServiceImpl {
+        let repository = MemorySessionRepository::new(MemoryDatabase::new());
+        SessionServiceImpl::new(Box::new(repository), Duration::from_secs(60))
+    }
+
+    #[tokio::test]
+    async fn test_refresh_rotates_the_token() {
+        let service = service();
+        let grant = service
+            .open_session(7, SessionClient::default())
+            .await
+            .unwrap();
+
+        let next = service.refresh(&grant.refresh_token).await.unwrap();
+        assert_eq!(next.user_id, 7);
+        assert!(next.refresh_token != grant.refresh_token);
+        assert!(service.refresh(&next.refresh_token).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_reusing_a_token_revokes_the_session() {
+        let service = service();
+        let grant = service
+            .open_session(7, SessionClient::default())
+            .await
+            .unwrap();
+        let next = service.refresh(&grant.refresh_token).await.unwrap();
+
+        assert_eq!(
+            service.refresh(&grant.refresh_token).await,
+            Err(UserError::InvalidRefreshToken)
+        );
+        assert_eq!(
+            service.refresh(&next.refresh_token).await,
+            Err(UserError::InvalidRefreshToken)
+        );
+        assert!(service.list_sessions(7).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_closed_sessions_cannot_be_refreshed() {
+        let service = service();
+        let grant = service
+            .open_session(7, SessionClient::default())
+            .await
+            .unwrap();
+
+        service.close_session(&grant.refresh_token).await.unwrap();
+        assert_eq!(
+            service.refresh(&grant.refresh_token).await,
+            Err(UserError::InvalidRefreshToken)
+        );
+        assert_eq!(service.close_session("garbage").await, Ok(()));
+        assert_eq!(
+            service.revoke_session(7, Uuid::new_v4()).await,
+            Err(UserError::SessionNotFound)
+        );
+    }
+}
@@ -0,0 +1,11 @@
+use crate::core::domain::user::User;
+use crate::core::errors::user_error::UserError;
+
+pub struct AccessToken {
+    pub token: String,
+    pub expires_in: u64,
+}
+
+pub trait TokenIssuer: Send + Sync {
+    fn issue(&self, user: &User) -> Result<AccessToken, UserError>;
+}
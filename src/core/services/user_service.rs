@@ -1,10 +1,38 @@
 use crate::core::domain::user::User;
+use crate::core::domain::user_query::{UserPage, UserQuery};
+use crate::core::domain::user_search::{UserMatch, UserSearch};
+use crate::core::errors::user_error::UserError;
+use async_trait::async_trait;
 
-pub trait UserService {
-    fn create_user(&self, id: i32, name: String, email: String) -> Result<User, String>;
-    fn find_user_by_id(&self, id: i32) -> Option<User>;
-    fn remove_user(&self, id: i32) -> Result<User, String>;
-    fn list_all_users(&self) -> Vec<User>;
-    fn get_last_user(&self) -> Option<User>;
-    fn update_user(&self, id: i32, name: String, email: String) -> Result<User, String>;
+#[async_trait]
+pub trait UserService: Send + Sync {
+    async fn create_user(
+        &self,
+        name: String,
+        email: String,
+        password: String,
+    ) -> Result<User, UserError>;
+    /// Resolves the account behind an email and password, or `InvalidCredentials`.
+    async fn authenticate(&self, email: &str, password: &str) -> Result<User, UserError>;
+    /// Replaces the password after re-checking the current one, or `InvalidCredentials`.
+    async fn change_password(
+        &self,
+        id: i32,
+        current_password: &str,
+        new_password: &str,
+    ) -> Result<(), UserError>;
+    /// Makes the account using `email` an admin, creating it without a password
+    /// when none exists; its owner then sets one through the password reset flow.
+    async fn ensure_admin(&self, email: &str) -> Result<User, UserError>;
+    async fn find_user_by_id(&self, id: i32) -> Result<User, UserError>;
+    async fn remove_user(&self, id: i32) -> Result<User, UserError>;
+    async fn list_all_users(&self, query: &UserQuery) -> Result<UserPage, UserError>;
+    async fn search_users(&self, search: &UserSearch) -> Result<Vec<UserMatch>, UserError>;
+    async fn update_user(&self, id: i32, name: String, email: String) -> Result<User, UserError>;
+    async fn patch_user(
+        &self,
+        id: i32,
+        name: Option<String>,
+        email: Option<String>,
+    ) -> Result<User, UserError>;
 }
@@ -1,51 +1,207 @@
-use crate::core::domain::user::User;
+use crate::core::domain::credentials::validate_password;
+use crate::core::domain::email::Email;
+use crate::core::domain::role::Role;
+use crate::core::domain::user::{validate_name, NewUser, User, UserPatch};
+use crate::core::domain::user_query::{UserPage, UserQuery};
+use crate::core::domain::user_search::{UserMatch, UserSearch};
+use crate::core::errors::user_error::{FieldError, UserError};
 use crate::core::repositories::user_repository::UserRepository;
+use crate::core::services::password_hasher::PasswordHasher;
 use crate::core::services::user_service::UserService;
+use async_trait::async_trait;
+use tracing::info;
 
 pub struct UserServiceImpl {
     repository: Box<dyn UserRepository>,
+    hasher: Box<dyn PasswordHasher>,
 }
 
 impl UserServiceImpl {
-    pub fn new(repository: Box<dyn UserRepository>) -> Self {
-        UserServiceImpl { repository }
+    pub fn new(repository: Box<dyn UserRepository>, hasher: Box<dyn PasswordHasher>) -> Self {
+        UserServiceImpl { repository, hasher }
+    }
+
+    /// Validates whichever fields are present, reporting every invalid one at once.
+    fn validate_fields(
+        name: Option<&str>,
+        email: Option<&str>,
+        password: Option<&str>,
+    ) -> Result<Option<String>, UserError> {
+        let mut errors = Vec::new();
+        if let Some(Err(message)) = name.map(validate_name) {
+            errors.push(FieldError::new("name", &message));
+        }
+        if let Some(Err(message)) = password.map(validate_password) {
+            errors.push(FieldError::new("password", &message));
+        }
+        let email = match email.map(Email::parse).transpose() {
+            Ok(email) => email.map(Email::into_string),
+            Err(error) => {
+                errors.push(FieldError::new("email", &error.to_string()));
+                None
+            }
+        };
+        if errors.is_empty() {
+            Ok(email)
+        } else {
+            Err(UserError::Validation(errors))
+        }
     }
 }
 
+#[async_trait]
 impl UserService for UserServiceImpl {
-    fn create_user(&self, id: i32, name: String, email: String) -> Result<User, String> {
-        let user = User::new(id, name, email);
+    async fn create_user(
+        &self,
+        name: String,
+        email: String,
+        password: String,
+    ) -> Result<User, UserError> {
+        let email =
+            Self::validate_fields(Some(&name), Some(&email), Some(&password))?.unwrap_or_default();
+        let hash = self.hasher.hash(&password).await?;
+        let user = NewUser::new(name, email).with_password_hash(hash);
+        self.repository.save_user(&user).await
+    }
 
-        if !user.validate_email() {
-            return Err("Invalid email address".to_string());
+    async fn authenticate(&self, email: &str, password: &str) -> Result<User, UserError> {
+        let credentials = self.repository.find_credentials_by_email(email).await?;
+        let hash = credentials
+            .as_ref()
+            .and_then(|credentials| credentials.password_hash.as_deref());
+        // Verify even without an account so both failures take the same time.
+        let verified = self.hasher.verify(password, hash).await;
+        match credentials {
+            Some(credentials) if verified => Ok(credentials.user),
+            _ => Err(UserError::InvalidCredentials),
         }
+    }
+
+    async fn change_password(
+        &self,
+        id: i32,
+        current_password: &str,
+        new_password: &str,
+    ) -> Result<(), UserError> {
+        if let Err(message) = validate_password(new_password) {
+            return Err(UserError::Validation(vec![FieldError::new(
+                "new_password",
+                &message,
+            )]));
+        }
+        let hash = self.repository.get_password_hash(id).await?;
+        if !self.hasher.verify(current_password, hash.as_deref()).await {
+            return Err(UserError::InvalidCredentials);
+        }
+        let hash = self.hasher.hash(new_password).await?;
+        self.repository.set_password_hash(id, &hash).await
+    }
 
-        self.repository.save_user(&user)
+    async fn ensure_admin(&self, email: &str) -> Result<User, UserError> {
+        let email = Self::validate_fields(None, Some(email), None)?.unwrap_or_default();
+        let user = match self.repository.find_credentials_by_email(&email).await? {
+            Some(credentials) => credentials.user,
+            None => {
+                info!("Creating the initial admin account; it has no password until reset");
+                let user = NewUser::new("Admin".to_string(), email);
+                self.repository.save_user(&user).await?
+            }
+        };
+        self.repository.grant_role(user.id, Role::Admin).await
     }
 
-    fn find_user_by_id(&self, id: i32) -> Option<User> {
-        self.repository.get_user_by_id(id)
+    async fn find_user_by_id(&self, id: i32) -> Result<User, UserError> {
+        self.repository.get_user_by_id(id).await
     }
 
-    fn remove_user(&self, id: i32) -> Result<User, String> {
-        self.repository.delete_user(id)
+    async fn remove_user(&self, id: i32) -> Result<User, UserError> {
+        self.repository.delete_user(id).await
     }
 
-    fn list_all_users(&self) -> Vec<User> {
-        self.repository.list_users()
+    async fn list_all_users(&self, query: &UserQuery) -> Result<UserPage, UserError> {
+        query.validate()?;
+        self.repository.list_users(query).await
     }
 
-    fn get_last_user(&self) -> Option<User> {
-        self.repository.get_last_user()
+    async fn search_users(&self, search: &UserSearch) -> Result<Vec<UserMatch>, UserError> {
+        search.validate()?;
+        self.repository.search(search).await
     }
 
-    fn update_user(&self, id: i32, name: String, email: String) -> Result<User, String> {
+    async fn update_user(&self, id: i32, name: String, email: String) -> Result<User, UserError> {
+        let email = Self::validate_fields(Some(&name), Some(&email), None)?.unwrap_or_default();
         let user = User::new(id, name, email);
+        self.repository.update_user(&user).await
+    }
 
-        if !user.validate_email() {
-            return Err("Invalid email address".to_string());
-        }
+    async fn patch_user(
+        &self,
+        id: i32,
+        name: Option<String>,
+        email: Option<String>,
+    ) -> Result<User, UserError> {
+        let email = Self::validate_fields(name.as_deref(), email.as_deref(), None)?;
+        let patch = UserPatch::new(name, email);
+        self.repository.patch_user(id, &patch).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::credentials_config::CredentialsConfig;
+    use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
+    use crate::infrastructure::database::memory::memory_database::MemoryDatabase;
+    use crate::infrastructure::database::memory::memory_user_repository::MemoryUserRepository;
+
+    fn service(database: &MemoryDatabase) -> UserServiceImpl {
+        UserServiceImpl::new(
+            Box::new(MemoryUserRepository::new(database.clone())),
+            Box::new(Argon2PasswordHasher::new(&CredentialsConfig {
+                memory_kib: 1024,
+                iterations: 1,
+                parallelism: 1,
+            })),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_ensure_admin_creates_a_passwordless_admin_once() {
+        let database = MemoryDatabase::new();
+        let service = service(&database);
+        let admin = service.ensure_admin("admin@email.com").await.unwrap();
+        assert_eq!(admin.email, "admin@email.com");
+        assert_eq!(admin.roles, vec![Role::Admin, Role::User]);
+        let repository = MemoryUserRepository::new(database);
+        assert_eq!(repository.get_password_hash(admin.id).await, Ok(None));
+
+        assert_eq!(service.ensure_admin("admin@email.com").await, Ok(admin));
+    }
+
+    #[tokio::test]
+    async fn test_ensure_admin_promotes_an_existing_user() {
+        let service = service(&MemoryDatabase::new());
+        let user = service
+            .create_user(
+                "John".to_string(),
+                "john@email.com".to_string(),
+                "john-password".to_string(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(user.roles, vec![Role::User]);
+
+        let admin = service.ensure_admin("JOHN@email.com").await.unwrap();
+        assert_eq!(admin.id, user.id);
+        assert_eq!(admin.roles, vec![Role::Admin, Role::User]);
+    }
 
-        self.repository.update_user(&user)
+    #[tokio::test]
+    async fn test_ensure_admin_rejects_invalid_emails() {
+        let service = service(&MemoryDatabase::new());
+        assert!(matches!(
+            service.ensure_admin("not-an-email").await,
+            Err(UserError::Validation(_))
+        ));
     }
 }
@@ -0,0 +1,107 @@
+use crate::config::credentials_config::CredentialsConfig;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::password_hasher::PasswordHasher;
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, SaltString};
+use argon2::{Algorithm, Argon2, Params, PasswordHasher as _, PasswordVerifier, Version};
+use async_trait::async_trait;
+use std::sync::OnceLock;
+
+/// Hash of a random password, verified against when an account has no hash.
+static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+
+/// Argon2id in PHC string format; the cost is stored in each hash, so hashes
+/// made under an older configuration keep verifying.
+pub struct Argon2PasswordHasher {
+    params: Params,
+}
+
+impl Argon2PasswordHasher {
+    pub fn new(config: &CredentialsConfig) -> Self {
+        Argon2PasswordHasher {
+            params: config.params().unwrap_or_default(),
+        }
+    }
+}
+
+fn hash_password(params: Params, password: &str) -> Result<String, UserError> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|error| UserError::Storage(format!("Failed to hash password: {}", error)))
+}
+
+fn verify_password(password: &str, hash: &str) -> bool {
+    PasswordHash::new(hash).is_ok_and(|hash| {
+        Argon2::default()
+            .verify_password(password.as_bytes(), &hash)
+            .is_ok()
+    })
+}
+
+#[async_trait]
+impl PasswordHasher for Argon2PasswordHasher {
+    async fn hash(&self, password: &str) -> Result<String, UserError> {
+        let params = self.params.clone();
+        let password = password.to_string();
+        tokio::task::spawn_blocking(move || hash_password(params, &password))
+            .await
+            .map_err(|error| UserError::Storage(format!("Hashing task failed: {}", error)))?
+    }
+
+    async fn verify(&self, password: &str, hash: Option<&str>) -> bool {
+        let params = self.params.clone();
+        let password = password.to_string();
+        let hash = hash.map(str::to_string);
+        tokio::task::spawn_blocking(move || match hash {
+            Some(hash) => verify_password(&password, &hash),
+            None => {
+                let dummy = DUMMY_HASH.get_or_init(|| {
+                    hash_password(params, "not-a-real-password").unwrap_or_default()
+                });
+                verify_password(&password, dummy);
+                false
+            }
+        })
+        .await
+        .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hasher() -> Argon2PasswordHasher {
+        Argon2PasswordHasher::new(&CredentialsConfig {
+            memory_kib: 1024,
+            iterations: 1,
+            parallelism: 1,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_hash_and_verify() {
+        let hasher = hasher();
+        let hash = hasher.hash("correct horse").await.unwrap();
+        assert!(hash.starts_with("$argon2id$v=19$m=1024,t=1,p=1$"));
+        assert!(hasher.verify("correct horse", Some(&hash)).await);
+        assert!(!hasher.verify("wrong horse", Some(&hash)).await);
+    }
+
+    #[tokio::test]
+    async fn test_hashes_are_salted() {
+        let hasher = hasher();
+        let first = hasher.hash("correct horse").await.unwrap();
+        let second = hasher.hash("correct horse").await.unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[tokio::test]
+    async fn test_missing_or_malformed_hashes_never_verify() {
+        let hasher = hasher();
+        assert!(!hasher.verify("not-a-real-password", None).await);
+        assert!(!hasher.verify("correct horse", Some("plaintext")).await);
+    }
+}
@@ -0,0 +1 @@
+pub mod argon2_password_hasher;
@@ -0,0 +1,15 @@
+use crate::config::settings::Settings;
+use crate::core::repositories::email_verification_repository::EmailVerificationRepository;
+use crate::infrastructure::database::memory::memory_email_verification_repository::MemoryEmailVerificationRepository;
+use crate::infrastructure::database::postgres::postgres_email_verification_repository::PostgresEmailVerificationRepository;
+
+pub fn email_verification_repository_factory(
+    settings: &Settings,
+) -> Box<dyn EmailVerificationRepository> {
+    match &settings.connection_pool {
+        None => Box::new(MemoryEmailVerificationRepository::new(
+            settings.memory_database.clone(),
+        )),
+        Some(pool) => Box::new(PostgresEmailVerificationRepository::new(pool.clone())),
+    }
+}
@@ -1 +1,4 @@
+pub mod email_verification_repository_factory;
+pub mod password_reset_repository_factory;
+pub mod session_repository_factory;
 pub mod user_repository_factory;
@@ -0,0 +1,13 @@
+use crate::config::settings::Settings;
+use crate::core::repositories::password_reset_repository::PasswordResetRepository;
+use crate::infrastructure::database::memory::memory_password_reset_repository::MemoryPasswordResetRepository;
+use crate::infrastructure::database::postgres::postgres_password_reset_repository::PostgresPasswordResetRepository;
+
+pub fn password_reset_repository_factory(settings: &Settings) -> Box<dyn PasswordResetRepository> {
+    match &settings.connection_pool {
+        None => Box::new(MemoryPasswordResetRepository::new(
+            settings.memory_database.clone(),
+        )),
+        Some(pool) => Box::new(PostgresPasswordResetRepository::new(pool.clone())),
+    }
+}
@@ -0,0 +1,13 @@
+use crate::config::settings::Settings;
+use crate::core::repositories::session_repository::SessionRepository;
+use crate::infrastructure::database::memory::memory_session_repository::MemorySessionRepository;
+use crate::infrastructure::database::postgres::postgres_session_repository::PostgresSessionRepository;
+
+pub fn session_repository_factory(settings: &Settings) -> Box<dyn SessionRepository> {
+    match &settings.connection_pool {
+        None => Box::new(MemorySessionRepository::new(
+            settings.memory_database.clone(),
+        )),
+        Some(pool) => Box::new(PostgresSessionRepository::new(pool.clone())),
+    }
+}
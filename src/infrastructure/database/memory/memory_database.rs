@@ -0,0 +1,56 @@
+use crate::core::domain::email_verification::EmailVerification;
+use crate::core::domain::password_reset::PasswordReset;
+use crate::core::domain::session::Session;
+use crate::core::domain::user::User;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicI32;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct MemoryDatabase {
+    users: Arc<Mutex<Vec<User>>>,
+    user_ids: Arc<AtomicI32>,
+    /// Kept apart from `users` so a cloned `User` never carries a hash.
+    password_hashes: Arc<Mutex<HashMap<i32, String>>>,
+    sessions: Arc<Mutex<HashMap<Uuid, Session>>>,
+    email_verifications: Arc<Mutex<HashMap<String, EmailVerification>>>,
+    password_resets: Arc<Mutex<HashMap<String, PasswordReset>>>,
+}
+
+impl MemoryDatabase {
+    pub fn new() -> Self {
+        MemoryDatabase {
+            users: Arc::new(Mutex::new(Vec::new())),
+            user_ids: Arc::new(AtomicI32::new(1)),
+            password_hashes: Arc::new(Mutex::new(HashMap::new())),
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+            email_verifications: Arc::new(Mutex::new(HashMap::new())),
+            password_resets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn users(&self) -> Arc<Mutex<Vec<User>>> {
+        self.users.clone()
+    }
+
+    pub fn user_ids(&self) -> Arc<AtomicI32> {
+        self.user_ids.clone()
+    }
+
+    pub fn password_hashes(&self) -> Arc<Mutex<HashMap<i32, String>>> {
+        self.password_hashes.clone()
+    }
+
+    pub fn sessions(&self) -> Arc<Mutex<HashMap<Uuid, Session>>> {
+        self.sessions.clone()
+    }
+
+    pub fn email_verifications(&self) -> Arc<Mutex<HashMap<String, EmailVerification>>> {
+        self.email_verifications.clone()
+    }
+
+    pub fn password_resets(&self) -> Arc<Mutex<HashMap<String, PasswordReset>>> {
+        self.password_resets.clone()
+    }
+}
@@ -0,0 +1,104 @@
+use crate::core::domain::email_verification::EmailVerification;
+use crate::core::errors::user_error::UserError;
+use crate::core::repositories::email_verification_repository::EmailVerificationRepository;
+use crate::infrastructure::database::memory::memory_database::MemoryDatabase;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tracing::instrument;
+
+pub struct MemoryEmailVerificationRepository {
+    verifications: Arc<Mutex<HashMap<String, EmailVerification>>>,
+}
+
+impl MemoryEmailVerificationRepository {
+    pub fn new(database: MemoryDatabase) -> Self {
+        MemoryEmailVerificationRepository {
+            verifications: database.email_verifications(),
+        }
+    }
+
+    fn lock_verifications(
+        &self,
+    ) -> Result<MutexGuard<'_, HashMap<String, EmailVerification>>, UserError> {
+        self.verifications
+            .lock()
+            .map_err(|_| UserError::Storage("Failed to lock email verifications".to_string()))
+    }
+}
+
+#[async_trait]
+impl EmailVerificationRepository for MemoryEmailVerificationRepository {
+    #[instrument(name = "memory.save_verification", skip_all, fields(user_id = verification.user_id))]
+    async fn save_verification(&self, verification: &EmailVerification) -> Result<(), UserError> {
+        self.lock_verifications()?
+            .insert(verification.token_hash.clone(), verification.clone());
+        Ok(())
+    }
+
+    #[instrument(name = "memory.use_verification", skip_all)]
+    async fn use_verification(
+        &self,
+        token_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<EmailVerification>, UserError> {
+        let mut verifications = self.lock_verifications()?;
+        match verifications.get_mut(token_hash) {
+            Some(verification) if verification.is_usable(now) => {
+                verification.used_at = Some(now);
+                Ok(Some(verification.clone()))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn verification(token_hash: &str, expires_in: Duration) -> EmailVerification {
+        let now = Utc::now();
+        EmailVerification {
+            token_hash: token_hash.to_string(),
+            user_id: 1,
+            email: "john@email.com".to_string(),
+            created_at: now,
+            expires_at: now + expires_in,
+            used_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verifications_are_single_use() {
+        let repository = MemoryEmailVerificationRepository::new(MemoryDatabase::new());
+        repository
+            .save_verification(&verification("hash-1", Duration::hours(1)))
+            .await
+            .unwrap();
+        let now = Utc::now();
+
+        let used = repository.use_verification("hash-1", now).await.unwrap();
+        assert_eq!(
+            used.map(|verification| verification.used_at),
+            Some(Some(now))
+        );
+        assert_eq!(repository.use_verification("hash-1", now).await, Ok(None));
+        assert_eq!(repository.use_verification("unknown", now).await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_expired_verifications_are_rejected() {
+        let repository = MemoryEmailVerificationRepository::new(MemoryDatabase::new());
+        repository
+            .save_verification(&verification("hash-1", Duration::seconds(-1)))
+            .await
+            .unwrap();
+        assert_eq!(
+            repository.use_verification("hash-1", Utc::now()).await,
+            Ok(None)
+        );
+    }
+}
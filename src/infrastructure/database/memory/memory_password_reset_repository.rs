@@ -0,0 +1,105 @@
+use crate::core::domain::password_reset::PasswordReset;
+use crate::core::errors::user_error::UserError;
+use crate::core::repositories::password_reset_repository::PasswordResetRepository;
+use crate::infrastructure::database::memory::memory_database::MemoryDatabase;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tracing::instrument;
+
+pub struct MemoryPasswordResetRepository {
+    resets: Arc<Mutex<HashMap<String, PasswordReset>>>,
+}
+
+impl MemoryPasswordResetRepository {
+    pub fn new(database: MemoryDatabase) -> Self {
+        MemoryPasswordResetRepository {
+            resets: database.password_resets(),
+        }
+    }
+
+    fn lock_resets(&self) -> Result<MutexGuard<'_, HashMap<String, PasswordReset>>, UserError> {
+        self.resets
+            .lock()
+            .map_err(|_| UserError::Storage("Failed to lock password resets".to_string()))
+    }
+}
+
+#[async_trait]
+impl PasswordResetRepository for MemoryPasswordResetRepository {
+    #[instrument(name = "memory.save_reset", skip_all, fields(user_id = reset.user_id))]
+    async fn save_reset(&self, reset: &PasswordReset) -> Result<(), UserError> {
+        self.lock_resets()?
+            .insert(reset.token_hash.clone(), reset.clone());
+        Ok(())
+    }
+
+    #[instrument(name = "memory.use_reset", skip_all)]
+    async fn use_reset(
+        &self,
+        token_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<PasswordReset>, UserError> {
+        let mut resets = self.lock_resets()?;
+        let Some(reset) = resets
+            .get(token_hash)
+            .filter(|reset| reset.is_usable(now))
+            .cloned()
+        else {
+            return Ok(None);
+        };
+        for other in resets.values_mut() {
+            if other.user_id == reset.user_id && other.used_at.is_none() {
+                other.used_at = Some(now);
+            }
+        }
+        Ok(resets.get(token_hash).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn reset(token_hash: &str, expires_in: Duration) -> PasswordReset {
+        let now = Utc::now();
+        PasswordReset {
+            token_hash: token_hash.to_string(),
+            user_id: 1,
+            created_at: now,
+            expires_at: now + expires_in,
+            used_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_using_a_reset_voids_the_others() {
+        let repository = MemoryPasswordResetRepository::new(MemoryDatabase::new());
+        repository
+            .save_reset(&reset("hash-1", Duration::hours(1)))
+            .await
+            .unwrap();
+        repository
+            .save_reset(&reset("hash-2", Duration::hours(1)))
+            .await
+            .unwrap();
+        let now = Utc::now();
+
+        let used = repository.use_reset("hash-2", now).await.unwrap();
+        assert_eq!(used.map(|reset| reset.used_at), Some(Some(now)));
+        assert_eq!(repository.use_reset("hash-2", now).await, Ok(None));
+        assert_eq!(repository.use_reset("hash-1", now).await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_expired_resets_are_rejected() {
+        let repository = MemoryPasswordResetRepository::new(MemoryDatabase::new());
+        repository
+            .save_reset(&reset("hash-1", Duration::seconds(-1)))
+            .await
+            .unwrap();
+        assert_eq!(repository.use_reset("hash-1", Utc::now()).await, Ok(None));
+    }
+}
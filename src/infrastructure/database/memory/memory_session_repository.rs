@@ -0,0 +1,188 @@
+use crate::core::domain::session::Session;
+use crate::core::errors::user_error::UserError;
+use crate::core::repositories::session_repository::SessionRepository;
+use crate::infrastructure::database::memory::memory_database::MemoryDatabase;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tracing::instrument;
+use uuid::Uuid;
+
+pub struct MemorySessionRepository {
+    sessions: Arc<Mutex<HashMap<Uuid, Session>>>,
+}
+
+impl MemorySessionRepository {
+    pub fn new(database: MemoryDatabase) -> Self {
+        MemorySessionRepository {
+            sessions: database.sessions(),
+        }
+    }
+
+    fn lock_sessions(&self) -> Result<MutexGuard<'_, HashMap<Uuid, Session>>, UserError> {
+        self.sessions
+            .lock()
+            .map_err(|_| UserError::Storage("Failed to lock sessions".to_string()))
+    }
+}
+
+#[async_trait]
+impl SessionRepository for MemorySessionRepository {
+    #[instrument(name = "memory.save_session", skip_all, fields(user_id = session.user_id))]
+    async fn save_session(&self, session: &Session) -> Result<(), UserError> {
+        self.lock_sessions()?.insert(session.id, session.clone());
+        Ok(())
+    }
+
+    #[instrument(name = "memory.find_session", skip_all)]
+    async fn find_session(&self, id: Uuid) -> Result<Option<Session>, UserError> {
+        Ok(self.lock_sessions()?.get(&id).cloned())
+    }
+
+    #[instrument(name = "memory.list_active_sessions", skip_all, fields(user_id = user_id))]
+    async fn list_active_sessions(
+        &self,
+        user_id: i32,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Session>, UserError> {
+        let mut sessions = self
+            .lock_sessions()?
+            .values()
+            .filter(|session| session.user_id == user_id && session.is_active(now))
+            .cloned()
+            .collect::<Vec<Session>>();
+        sessions.sort_by_key(|session| Reverse(session.created_at));
+        Ok(sessions)
+    }
+
+    #[instrument(name = "memory.rotate_refresh_token", skip_all)]
+    async fn rotate_refresh_token(
+        &self,
+        id: Uuid,
+        current_hash: &str,
+        new_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<bool, UserError> {
+        let mut sessions = self.lock_sessions()?;
+        match sessions.get_mut(&id) {
+            Some(session)
+                if session.revoked_at.is_none() && session.refresh_token_hash == current_hash =>
+            {
+                session.refresh_token_hash = new_hash.to_string();
+                session.last_used_at = now;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    #[instrument(name = "memory.revoke_session", skip_all, fields(user_id = user_id))]
+    async fn revoke_session(
+        &self,
+        user_id: i32,
+        id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<bool, UserError> {
+        let mut sessions = self.lock_sessions()?;
+        match sessions.get_mut(&id) {
+            Some(session) if session.user_id == user_id && session.revoked_at.is_none() => {
+                session.revoked_at = Some(now);
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    #[instrument(name = "memory.revoke_user_sessions", skip_all, fields(user_id = user_id))]
+    async fn revoke_user_sessions(
+        &self,
+        user_id: i32,
+        now: DateTime<Utc>,
+    ) -> Result<usize, UserError> {
+        let mut sessions = self.lock_sessions()?;
+        let mut revoked = 0;
+        for session in sessions.values_mut() {
+            if session.user_id == user_id && session.revoked_at.is_none() {
+                session.revoked_at = Some(now);
+                revoked += 1;
+            }
+        }
+        Ok(revoked)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn session(user_id: i32, hash: &str) -> Session {
+        let now = Utc::now();
+        Session {
+            id: Uuid::new_v4(),
+            user_id,
+            refresh_token_hash: hash.to_string(),
+            user_agent: Some("curl/8.0".to_string()),
+            ip_address: Some("127.0.0.1".to_string()),
+            created_at: now,
+            last_used_at: now,
+            expires_at: now + Duration::days(1),
+            revoked_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rotation_only_succeeds_once() {
+        let repository = MemorySessionRepository::new(MemoryDatabase::new());
+        let session = session(1, "hash-1");
+        repository.save_session(&session).await.unwrap();
+        let now = Utc::now();
+
+        assert_eq!(
+            repository
+                .rotate_refresh_token(session.id, "hash-1", "hash-2", now)
+                .await,
+            Ok(true)
+        );
+        assert_eq!(
+            repository
+                .rotate_refresh_token(session.id, "hash-1", "hash-3", now)
+                .await,
+            Ok(false)
+        );
+        let stored = repository.find_session(session.id).await.unwrap().unwrap();
+        assert_eq!(stored.refresh_token_hash, "hash-2");
+    }
+
+    #[tokio::test]
+    async fn test_revoked_sessions_are_not_listed() {
+        let repository = MemorySessionRepository::new(MemoryDatabase::new());
+        let first = session(1, "hash-1");
+        let second = session(1, "hash-2");
+        repository.save_session(&first).await.unwrap();
+        repository.save_session(&second).await.unwrap();
+        repository
+            .save_session(&session(2, "hash-3"))
+            .await
+            .unwrap();
+        let now = Utc::now();
+
+        assert_eq!(repository.revoke_session(2, first.id, now).await, Ok(false));
+        assert_eq!(repository.revoke_session(1, first.id, now).await, Ok(true));
+        let active = repository.list_active_sessions(1, now).await.unwrap();
+        assert_eq!(active, vec![second]);
+
+        assert_eq!(repository.revoke_user_sessions(1, now).await, Ok(1));
+        assert!(repository
+            .list_active_sessions(1, now)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            repository.list_active_sessions(2, now).await.unwrap().len(),
+            1
+        );
+    }
+}
@@ -1,157 +1,816 @@
-use crate::core::domain::user::User;
+use crate::core::domain::credentials::UserCredentials;
+use crate::core::domain::email_verification::EmailVerification;
+use crate::core::domain::password_reset::PasswordReset;
+use crate::core::domain::role::Role;
+use crate::core::domain::session::Session;
+use crate::core::domain::user::{NewUser, User, UserPatch};
+use crate::core::domain::user_query::{
+    page_offset, SortDirection, UserPage, UserPagination, UserQuery, UserSortField,
+};
+use crate::core::domain::user_search::{UserMatch, UserSearch};
+use crate::core::errors::user_error::UserError;
 use crate::core::repositories::user_repository::UserRepository;
-use std::sync::{Arc, Mutex};
+use crate::infrastructure::database::memory::memory_database::MemoryDatabase;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering as SortOrdering;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use tracing::instrument;
+use uuid::Uuid;
 
 pub struct MemoryUserRepository {
     users: Arc<Mutex<Vec<User>>>,
+    user_ids: Arc<AtomicI32>,
+    password_hashes: Arc<Mutex<HashMap<i32, String>>>,
+    sessions: Arc<Mutex<HashMap<Uuid, Session>>>,
+    email_verifications: Arc<Mutex<HashMap<String, EmailVerification>>>,
+    password_resets: Arc<Mutex<HashMap<String, PasswordReset>>>,
 }
 
 impl MemoryUserRepository {
-    pub fn new() -> Self {
+    pub fn new(database: MemoryDatabase) -> Self {
         MemoryUserRepository {
-            users: Arc::new(Mutex::new(Vec::new())),
+            users: database.users(),
+            user_ids: database.user_ids(),
+            password_hashes: database.password_hashes(),
+            sessions: database.sessions(),
+            email_verifications: database.email_verifications(),
+            password_resets: database.password_resets(),
         }
     }
 }
 
+#[async_trait]
 impl UserRepository for MemoryUserRepository {
-    fn save_user(&self, user: &User) -> Result<User, String> {
-        let mut users = self
-            .users
-            .lock()
-            .map_err(|_| "Failed to lock users".to_string())?;
-        users.push(self.clone_user(user));
-        Ok(self.clone_user(user))
+    #[instrument(name = "memory.save_user", skip_all)]
+    async fn save_user(&self, user: &NewUser) -> Result<User, UserError> {
+        let mut users = self.lock_users()?;
+        self.ensure_email_available(&users, &user.email, None)?;
+        let id = self.user_ids.fetch_add(1, Ordering::SeqCst);
+        if let Some(password_hash) = &user.password_hash {
+            self.lock_password_hashes()?
+                .insert(id, password_hash.clone());
+        }
+        let user =
+            User::new(id, user.name.clone(), user.email.clone()).with_roles(user.roles.clone());
+        users.push(self.clone_user(&user));
+        Ok(user)
     }
 
-    fn get_user_by_id(&self, id: i32) -> Option<User> {
-        let users = self
-            .users
-            .lock()
-            .map_err(|_| "Failed to lock users".to_string())
-            .ok()?;
+    #[instrument(name = "memory.search", skip_all)]
+    async fn search(&self, search: &UserSearch) -> Result<Vec<UserMatch>, UserError> {
+        let users = self.lock_users()?;
+        let term = search.term.to_lowercase();
+        let mut matches = users
+            .iter()
+            .map(|user| UserMatch {
+                user: self.clone_user(user),
+                score: self
+                    .relevance(&user.name, &term)
+                    .max(self.relevance(&user.email, &term)),
+            })
+            .filter(|user_match| user_match.score > 0.0)
+            .collect::<Vec<UserMatch>>();
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.user.id.cmp(&b.user.id)));
+        matches.truncate(search.limit as usize);
+        Ok(matches)
+    }
+
+    #[instrument(name = "memory.get_user_by_id", skip_all, fields(user_id = id))]
+    async fn get_user_by_id(&self, id: i32) -> Result<User, UserError> {
+        let users = self.lock_users()?;
         users
             .iter()
             .find(|user| user.id == id)
             .map(|user| self.clone_user(user))
+            .ok_or(UserError::NotFound)
     }
 
-    fn delete_user(&self, id: i32) -> Result<User, String> {
-        let mut users = self
-            .users
-            .lock()
-            .map_err(|_| "Failed to lock users".to_string())?;
+    #[instrument(name = "memory.delete_user", skip_all, fields(user_id = id))]
+    async fn delete_user(&self, id: i32) -> Result<User, UserError> {
+        let mut users = self.lock_users()?;
         if let Some(pos) = users.iter().position(|user| user.id == id) {
             let user = self.clone_user(&users[pos]);
             users.remove(pos);
+            self.lock_password_hashes()?.remove(&id);
+            // Mirrors the ON DELETE CASCADE of the postgres schema.
+            self.sessions
+                .lock()
+                .map_err(|_| UserError::Storage("Failed to lock sessions".to_string()))?
+                .retain(|_, session| session.user_id != id);
+            self.email_verifications
+                .lock()
+                .map_err(|_| UserError::Storage("Failed to lock email verifications".to_string()))?
+                .retain(|_, verification| verification.user_id != id);
+            self.password_resets
+                .lock()
+                .map_err(|_| UserError::Storage("Failed to lock password resets".to_string()))?
+                .retain(|_, reset| reset.user_id != id);
             Ok(user)
         } else {
-            Err("User not found".to_string())
+            Err(UserError::NotFound)
         }
     }
 
-    fn list_users(&self) -> Vec<User> {
-        let users = self.users.lock().unwrap();
-        users.iter().map(|user| self.clone_user(user)).collect()
-    }
+    #[instrument(name = "memory.list_users", skip_all)]
+    async fn list_users(&self, query: &UserQuery) -> Result<UserPage, UserError> {
+        let users = self.lock_users()?;
+        let mut matching = users
+            .iter()
+            .filter(|user| self.matches(user, query))
+            .collect::<Vec<&User>>();
+        matching.sort_by(|a, b| self.compare(a, b, query));
+        let total = matching.len() as i64;
 
-    fn get_last_user(&self) -> Option<User> {
-        let users = self.users.lock().unwrap();
-        users
-            .last()
-            .map(|user| Some(self.clone_user(user)))
-            .unwrap_or(None)
+        let (page, next_cursor) = match query.pagination {
+            UserPagination::Cursor { after, limit } => {
+                let mut page = matching
+                    .into_iter()
+                    .filter(|user| match (after, query.direction) {
+                        (None, _) => true,
+                        (Some(after), SortDirection::Asc) => user.id > after,
+                        (Some(after), SortDirection::Desc) => user.id < after,
+                    })
+                    .take(limit as usize + 1)
+                    .collect::<Vec<&User>>();
+                let next_cursor = if page.len() as i64 > limit {
+                    page.truncate(limit as usize);
+                    // Only an id-sorted listing can be resumed from a cursor.
+                    page.last()
+                        .map(|user| user.id)
+                        .filter(|_| query.sort == UserSortField::Id)
+                } else {
+                    None
+                };
+                (page, next_cursor)
+            }
+            UserPagination::Page { page, per_page } => {
+                let offset = page_offset(page, per_page)
+                    .map_err(|error| UserError::Validation(vec![error]))?;
+                let page = matching
+                    .into_iter()
+                    .skip(offset as usize)
+                    .take(per_page as usize)
+                    .collect::<Vec<&User>>();
+                (page, None)
+            }
+        };
+
+        Ok(UserPage {
+            users: page.into_iter().map(|user| self.clone_user(user)).collect(),
+            total,
+            next_cursor,
+        })
     }
 
-    fn update_user(&self, user: &User) -> Result<User, String> {
-        let mut users = self
-            .users
-            .lock()
-            .map_err(|_| "Failed to lock users".to_string())?;
+    #[instrument(name = "memory.update_user", skip_all, fields(user_id = user.id))]
+    async fn update_user(&self, user: &User) -> Result<User, UserError> {
+        let mut users = self.lock_users()?;
         if let Some(pos) = users.iter().position(|u| u.id == user.id) {
-            users[pos] = self.clone_user(user);
-            Ok(self.clone_user(user))
+            self.ensure_email_available(&users, &user.email, Some(user.id))?;
+            let roles = users[pos].roles.clone();
+            let verified_at = users[pos]
+                .email_verified_at
+                .filter(|_| users[pos].email == user.email);
+            users[pos] = self
+                .clone_user(user)
+                .with_roles(roles)
+                .with_email_verified_at(verified_at);
+            Ok(self.clone_user(&users[pos]))
         } else {
-            Err("User not found".to_string())
+            Err(UserError::NotFound)
         }
     }
 
+    #[instrument(name = "memory.patch_user", skip_all, fields(user_id = id))]
+    async fn patch_user(&self, id: i32, patch: &UserPatch) -> Result<User, UserError> {
+        let mut users = self.lock_users()?;
+        let pos = users
+            .iter()
+            .position(|user| user.id == id)
+            .ok_or(UserError::NotFound)?;
+        if let Some(email) = &patch.email {
+            self.ensure_email_available(&users, email, Some(id))?;
+        }
+        let user = &mut users[pos];
+        if let Some(name) = &patch.name {
+            user.name = name.clone();
+        }
+        if let Some(email) = patch.email.as_ref().filter(|email| **email != user.email) {
+            user.email = email.clone();
+            user.email_verified_at = None;
+        }
+        Ok(self.clone_user(user))
+    }
+
+    #[instrument(name = "memory.grant_role", skip_all, fields(user_id = id))]
+    async fn grant_role(&self, id: i32, role: Role) -> Result<User, UserError> {
+        let mut users = self.lock_users()?;
+        let user = users
+            .iter_mut()
+            .find(|user| user.id == id)
+            .ok_or(UserError::NotFound)?;
+        if !user.roles.contains(&role) {
+            user.roles.push(role);
+            user.roles.sort();
+        }
+        Ok(self.clone_user(user))
+    }
+
+    #[instrument(name = "memory.find_credentials_by_email", skip_all)]
+    async fn find_credentials_by_email(
+        &self,
+        email: &str,
+    ) -> Result<Option<UserCredentials>, UserError> {
+        let users = self.lock_users()?;
+        let email = email.to_lowercase();
+        let Some(user) = users.iter().find(|user| user.email.to_lowercase() == email) else {
+            return Ok(None);
+        };
+        Ok(Some(UserCredentials {
+            user: self.clone_user(user),
+            password_hash: self.lock_password_hashes()?.get(&user.id).cloned(),
+        }))
+    }
+
+    #[instrument(name = "memory.get_password_hash", skip_all, fields(user_id = id))]
+    async fn get_password_hash(&self, id: i32) -> Result<Option<String>, UserError> {
+        let users = self.lock_users()?;
+        if !users.iter().any(|user| user.id == id) {
+            return Err(UserError::NotFound);
+        }
+        Ok(self.lock_password_hashes()?.get(&id).cloned())
+    }
+
+    #[instrument(name = "memory.set_password_hash", skip_all, fields(user_id = id))]
+    async fn set_password_hash(&self, id: i32, password_hash: &str) -> Result<(), UserError> {
+        let users = self.lock_users()?;
+        if !users.iter().any(|user| user.id == id) {
+            return Err(UserError::NotFound);
+        }
+        self.lock_password_hashes()?
+            .insert(id, password_hash.to_string());
+        Ok(())
+    }
+
+    #[instrument(name = "memory.mark_email_verified", skip_all, fields(user_id = id))]
+    async fn mark_email_verified(
+        &self,
+        id: i32,
+        email: &str,
+        verified_at: DateTime<Utc>,
+    ) -> Result<bool, UserError> {
+        let mut users = self.lock_users()?;
+        let user = users
+            .iter_mut()
+            .find(|user| user.id == id)
+            .ok_or(UserError::NotFound)?;
+        if user.email != email {
+            return Ok(false);
+        }
+        user.email_verified_at = Some(verified_at);
+        Ok(true)
+    }
+
     #[cfg(test)]
-    fn drop_database(&self) -> Result<(), String> {
-        let mut users = self
-            .users
-            .lock()
-            .map_err(|_| "Failed to lock users".to_string())?;
+    async fn drop_database(&self) -> Result<(), UserError> {
+        let mut users = self.lock_users()?;
         users.clear();
+        self.lock_password_hashes()?.clear();
+        self.sessions
+            .lock()
+            .map_err(|_| UserError::Storage("Failed to lock sessions".to_string()))?
+            .clear();
+        self.email_verifications
+            .lock()
+            .map_err(|_| UserError::Storage("Failed to lock email verifications".to_string()))?
+            .clear();
+        self.password_resets
+            .lock()
+            .map_err(|_| UserError::Storage("Failed to lock password resets".to_string()))?
+            .clear();
+        self.user_ids.store(1, Ordering::SeqCst);
         Ok(())
     }
 }
 
 impl MemoryUserRepository {
+    fn lock_users(&self) -> Result<MutexGuard<'_, Vec<User>>, UserError> {
+        self.users
+            .lock()
+            .map_err(|_| UserError::Storage("Failed to lock users".to_string()))
+    }
+
+    /// Always taken after `users` when both are needed.
+    fn lock_password_hashes(&self) -> Result<MutexGuard<'_, HashMap<i32, String>>, UserError> {
+        self.password_hashes
+            .lock()
+            .map_err(|_| UserError::Storage("Failed to lock password hashes".to_string()))
+    }
+
+    fn ensure_email_available(
+        &self,
+        users: &[User],
+        email: &str,
+        except: Option<i32>,
+    ) -> Result<(), UserError> {
+        let taken = users.iter().any(|user| {
+            Some(user.id) != except && user.email.to_lowercase() == email.to_lowercase()
+        });
+        if taken {
+            Err(UserError::email_taken())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn matches(&self, user: &User, query: &UserQuery) -> bool {
+        let domain_matches = query.email_domain.as_ref().is_none_or(|domain| {
+            user.email
+                .to_lowercase()
+                .ends_with(&format!("@{}", domain.to_lowercase()))
+        });
+        let name_matches = query
+            .name_contains
+            .as_ref()
+            .is_none_or(|fragment| user.name.to_lowercase().contains(&fragment.to_lowercase()));
+        domain_matches && name_matches
+    }
+
+    fn relevance(&self, value: &str, term: &str) -> f32 {
+        let value = value.to_lowercase();
+        if value == term {
+            1.0
+        } else if value.starts_with(term) {
+            0.75
+        } else if value.contains(term) {
+            0.5
+        } else {
+            0.0
+        }
+    }
+
+    fn compare(&self, a: &User, b: &User, query: &UserQuery) -> SortOrdering {
+        let ordering = match query.sort {
+            UserSortField::Id => a.id.cmp(&b.id),
+            UserSortField::Name => a.name.cmp(&b.name).then(a.id.cmp(&b.id)),
+            UserSortField::Email => a.email.cmp(&b.email).then(a.id.cmp(&b.id)),
+        };
+        match query.direction {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
+        }
+    }
+
     fn clone_user(&self, user: &User) -> User {
         User::new(user.id, user.name.clone(), user.email.clone())
+            .with_roles(user.roles.clone())
+            .with_email_verified_at(user.email_verified_at)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::domain::role::Role;
 
-    #[test]
-    fn test_save_user() {
-        let repository = MemoryUserRepository::new();
-        repository.drop_database().unwrap();
-        let user = User::new(1, "John".to_string(), "john@email.com".to_string());
-        assert_eq!(repository.save_user(&user), Ok(user));
+    fn new_user(name: &str, email: &str) -> NewUser {
+        NewUser::new(name.to_string(), email.to_string())
     }
 
-    #[test]
-    fn test_get_user_by_id() {
-        let repository = MemoryUserRepository::new();
-        repository.drop_database().unwrap();
+    #[tokio::test]
+    async fn test_save_user() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
         let user = User::new(1, "John".to_string(), "john@email.com".to_string());
-        repository.save_user(&user).unwrap();
-        assert_eq!(repository.get_user_by_id(1), Some(user));
+        assert_eq!(
+            repository
+                .save_user(&new_user("John", "john@email.com"))
+                .await,
+            Ok(user)
+        );
     }
 
-    #[test]
-    fn test_delete_user() {
-        let repository = MemoryUserRepository::new();
-        repository.drop_database().unwrap();
-        let user = User::new(1, "John".to_string(), "john@email.com".to_string());
-        repository.save_user(&user).unwrap();
-        assert_eq!(repository.delete_user(1), Ok(user));
-    }
-
-    #[test]
-    fn test_list_users() {
-        let repository = MemoryUserRepository::new();
-        repository.drop_database().unwrap();
-        let user1 = User::new(1, "John".to_string(), "john@email.com".to_string());
-        let user2 = User::new(2, "Jane".to_string(), "jane@email.com".to_string());
-        repository.save_user(&user1).unwrap();
-        repository.save_user(&user2).unwrap();
-        assert_eq!(repository.list_users(), vec![user1, user2]);
-    }
-
-    #[test]
-    fn test_get_last_user_id() {
-        let repository = MemoryUserRepository::new();
-        repository.drop_database().unwrap();
-        let user1 = User::new(1, "John".to_string(), "john@email.com".to_string());
-        let user2 = User::new(2, "Jane".to_string(), "jane@email.com".to_string());
-        repository.save_user(&user1).unwrap();
-        repository.save_user(&user2).unwrap();
-        assert_eq!(repository.get_last_user().unwrap(), user2);
-    }
-
-    #[test]
-    fn test_update_user() {
-        let repository = MemoryUserRepository::new();
-        repository.drop_database().unwrap();
+    #[tokio::test]
+    async fn test_save_user_assigns_sequential_ids() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        let user1 = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        let user2 = repository
+            .save_user(&new_user("Jane", "jane@email.com"))
+            .await
+            .unwrap();
+        assert_eq!((user1.id, user2.id), (1, 2));
+    }
+
+    #[tokio::test]
+    async fn test_get_user_by_id() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        assert_eq!(repository.get_user_by_id(user.id).await, Ok(user));
+    }
+
+    #[tokio::test]
+    async fn test_delete_user() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        assert_eq!(repository.delete_user(user.id).await, Ok(user));
+    }
+
+    #[tokio::test]
+    async fn test_list_users() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        let user1 = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        let user2 = repository
+            .save_user(&new_user("Jane", "jane@email.com"))
+            .await
+            .unwrap();
+        assert_eq!(
+            repository
+                .list_users(&UserQuery::default())
+                .await
+                .map(|page| page.users),
+            Ok(vec![user1, user2])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_users_with_cursor() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        for (name, email) in [
+            ("John", "john@email.com"),
+            ("Jane", "jane@email.com"),
+            ("Jack", "jack@email.com"),
+        ] {
+            repository.save_user(&new_user(name, email)).await.unwrap();
+        }
+        let mut query = UserQuery {
+            pagination: UserPagination::Cursor {
+                after: None,
+                limit: 2,
+            },
+            ..UserQuery::default()
+        };
+        let first = repository.list_users(&query).await.unwrap();
+        assert_eq!(first.total, 3);
+        assert_eq!(first.users.len(), 2);
+        assert_eq!(first.next_cursor, Some(2));
+
+        query.pagination = UserPagination::Cursor {
+            after: first.next_cursor,
+            limit: 2,
+        };
+        let second = repository.list_users(&query).await.unwrap();
+        assert_eq!(
+            second
+                .users
+                .iter()
+                .map(|user| user.id)
+                .collect::<Vec<i32>>(),
+            vec![3]
+        );
+        assert_eq!(second.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn test_list_users_sorted_by_name_has_no_cursor() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        for (user_name, user_email) in [("John", "john@email.com"), ("Jane", "jane@email.com")] {
+            repository
+                .save_user(&new_user(user_name, user_email))
+                .await
+                .unwrap();
+        }
+        let query = UserQuery {
+            pagination: UserPagination::Cursor {
+                after: None,
+                limit: 1,
+            },
+            sort: UserSortField::Name,
+            ..UserQuery::default()
+        };
+        let page = repository.list_users(&query).await.unwrap();
+        assert_eq!(page.users.len(), 1);
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn test_list_users_rejects_overflowing_pages() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        let query = UserQuery {
+            pagination: UserPagination::Page {
+                page: i64::MAX,
+                per_page: 100,
+            },
+            ..UserQuery::default()
+        };
+        assert!(matches!(
+            repository.list_users(&query).await,
+            Err(UserError::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_users_filtered_and_sorted() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        for (name, email) in [
+            ("John", "john@email.com"),
+            ("Jane", "jane@example.com"),
+            ("Johanna", "johanna@EMAIL.com"),
+        ] {
+            repository.save_user(&new_user(name, email)).await.unwrap();
+        }
+        let query = UserQuery {
+            pagination: UserPagination::Page {
+                page: 1,
+                per_page: 10,
+            },
+            sort: UserSortField::Name,
+            direction: SortDirection::Desc,
+            email_domain: Some("email.com".to_string()),
+            name_contains: Some("jo".to_string()),
+        };
+        let page = repository.list_users(&query).await.unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(
+            page.users
+                .iter()
+                .map(|user| user.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["John", "Johanna"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_ranks_closer_matches_first() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        for (name, email) in [
+            ("Maryjohn", "mary@email.com"),
+            ("Jane", "jane@email.com"),
+            ("John", "john@email.com"),
+        ] {
+            repository.save_user(&new_user(name, email)).await.unwrap();
+        }
+        let matches = repository
+            .search(&UserSearch::new("JOHN", None))
+            .await
+            .unwrap();
+        assert_eq!(
+            matches
+                .iter()
+                .map(|user_match| (user_match.user.name.as_str(), user_match.score))
+                .collect::<Vec<(&str, f32)>>(),
+            vec![("John", 1.0), ("Maryjohn", 0.5)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_user() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        let updated_user = User::new(
+            user.id,
+            "John Doe".to_string(),
+            "john-doe@email.com".to_string(),
+        );
+        assert_eq!(
+            repository.update_user(&updated_user).await,
+            Ok(updated_user)
+        );
+        assert_eq!(
+            repository
+                .get_user_by_id(user.id)
+                .await
+                .map(|user| user.name),
+            Ok("John Doe".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_roles_are_kept_across_updates() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        assert_eq!(user.roles, vec![Role::User]);
+        let updated_user = User::new(user.id, "John Doe".to_string(), user.email.clone());
+        assert_eq!(
+            repository
+                .update_user(&updated_user)
+                .await
+                .map(|user| user.roles),
+            Ok(vec![Role::User])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_grant_role() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            assert_eq!(
+                repository
+                    .grant_role(user.id, Role::Admin)
+                    .await
+                    .map(|user| user.roles),
+                Ok(vec![Role::Admin, Role::User])
+            );
+        }
+        assert_eq!(
+            repository.grant_role(user.id + 1, Role::Admin).await,
+            Err(UserError::NotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_changing_the_email_clears_its_verification() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        assert_eq!(user.email_verified_at, None);
+        let now = chrono::Utc::now();
+        assert_eq!(
+            repository
+                .mark_email_verified(user.id, "old@email.com", now)
+                .await,
+            Ok(false)
+        );
+        assert_eq!(
+            repository
+                .mark_email_verified(user.id, "john@email.com", now)
+                .await,
+            Ok(true)
+        );
+
+        let renamed = UserPatch::new(Some("John Doe".to_string()), None);
+        let user = repository.patch_user(user.id, &renamed).await.unwrap();
+        assert!(user.email_verified_at.is_some());
+
+        let moved = UserPatch::new(None, Some("john.doe@email.com".to_string()));
+        let user = repository.patch_user(user.id, &moved).await.unwrap();
+        assert_eq!(user.email_verified_at, None);
+    }
+
+    #[tokio::test]
+    async fn test_password_hashes() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com").with_password_hash("hash-1".to_string()))
+            .await
+            .unwrap();
+        let credentials = repository
+            .find_credentials_by_email("JOHN@email.com")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(credentials.user.id, user.id);
+        assert_eq!(credentials.password_hash.as_deref(), Some("hash-1"));
+
+        repository
+            .set_password_hash(user.id, "hash-2")
+            .await
+            .unwrap();
+        assert_eq!(
+            repository.get_password_hash(user.id).await,
+            Ok(Some("hash-2".to_string()))
+        );
+        assert_eq!(
+            repository.find_credentials_by_email("jane@email.com").await,
+            Ok(None)
+        );
+        assert_eq!(
+            repository.set_password_hash(user.id + 1, "hash-3").await,
+            Err(UserError::NotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_patch_user() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        let patch = UserPatch::new(Some("John Doe".to_string()), None);
+        assert_eq!(
+            repository.patch_user(user.id, &patch).await,
+            Ok(User::new(
+                user.id,
+                "John Doe".to_string(),
+                "john@email.com".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_patch_missing_user_is_not_found() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        assert_eq!(
+            repository.patch_user(1, &UserPatch::default()).await,
+            Err(UserError::NotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_missing_user_is_not_found() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
         let user = User::new(1, "John".to_string(), "john@email.com".to_string());
-        repository.save_user(&user).unwrap();
-        let updated_user = User::new(1, "John Doe".to_string(), "john-doe@email.com".to_string());
-        assert_eq!(repository.update_user(&updated_user), Ok(updated_user));
+        assert_eq!(
+            repository.update_user(&user).await,
+            Err(UserError::NotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_duplicate_email_is_conflict() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        assert_eq!(
+            repository
+                .save_user(&new_user("Johnny", "John@Email.com"))
+                .await,
+            Err(UserError::email_taken())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_to_taken_email_is_conflict() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        let john = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        let jane = repository
+            .save_user(&new_user("Jane", "jane@email.com"))
+            .await
+            .unwrap();
+        let patch = UserPatch::new(None, Some("JOHN@email.com".to_string()));
+        assert_eq!(
+            repository.patch_user(jane.id, &patch).await,
+            Err(UserError::email_taken())
+        );
+        let renamed = User::new(john.id, "Johnny".to_string(), "John@email.com".to_string());
+        assert_eq!(repository.update_user(&renamed).await, Ok(renamed));
+    }
+
+    #[tokio::test]
+    async fn test_get_missing_user_is_not_found() {
+        let repository = MemoryUserRepository::new(MemoryDatabase::new());
+        repository.drop_database().await.unwrap();
+        assert_eq!(repository.get_user_by_id(1).await, Err(UserError::NotFound));
+    }
+
+    #[tokio::test]
+    async fn test_repositories_share_database() {
+        let database = MemoryDatabase::new();
+        let writer = MemoryUserRepository::new(database.clone());
+        let reader = MemoryUserRepository::new(database);
+        let user = writer
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        assert_eq!(reader.get_user_by_id(user.id).await, Ok(user));
     }
 }
@@ -1 +1,5 @@
+pub mod memory_database;
+pub mod memory_email_verification_repository;
+pub mod memory_password_reset_repository;
+pub mod memory_session_repository;
 pub mod memory_user_repository;
@@ -0,0 +1,16 @@
+use std::process::Command;
+
+/// Development only: brings up the `db` service from `docker-compose.yml`.
+/// Readiness is still checked through the connection pool afterwards.
+pub fn start_database() -> Result<(), String> {
+    let status = Command::new("docker")
+        .args(["compose", "up", "-d", "db"])
+        .status()
+        .map_err(|error| format!("Failed to run docker compose: {}", error))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("docker compose up exited with {}", status))
+    }
+}
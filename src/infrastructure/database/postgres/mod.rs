@@ -1,2 +1,8 @@
+pub mod database_health;
 pub mod database_manager;
+pub mod docker_compose;
+pub mod postgres_email_verification_repository;
+pub mod postgres_password_reset_repository;
+pub mod postgres_session_repository;
 pub mod postgres_user_repository;
+pub mod query_runner;
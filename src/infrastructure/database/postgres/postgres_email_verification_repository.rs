@@ -0,0 +1,154 @@
+use crate::core::domain::email_verification::EmailVerification;
+use crate::core::errors::user_error::UserError;
+use crate::core::repositories::email_verification_repository::EmailVerificationRepository;
+use crate::infrastructure::database::postgres::database_manager::ConnectionPool;
+use crate::infrastructure::database::postgres::query_runner::run_query;
+use crate::schema::email_verification_tokens::dsl::*;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::{Insertable, PgConnection, QueryDsl, Queryable, RunQueryDsl};
+use tracing::instrument;
+
+#[derive(Insertable, Queryable)]
+#[diesel(table_name = crate::schema::email_verification_tokens)]
+struct EmailVerificationEntity {
+    pub token_hash: String,
+    pub user_id: i32,
+    pub email: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl From<&EmailVerification> for EmailVerificationEntity {
+    fn from(verification: &EmailVerification) -> Self {
+        EmailVerificationEntity {
+            token_hash: verification.token_hash.clone(),
+            user_id: verification.user_id,
+            email: verification.email.clone(),
+            created_at: verification.created_at,
+            expires_at: verification.expires_at,
+            used_at: verification.used_at,
+        }
+    }
+}
+
+impl From<EmailVerificationEntity> for EmailVerification {
+    fn from(entity: EmailVerificationEntity) -> Self {
+        EmailVerification {
+            token_hash: entity.token_hash,
+            user_id: entity.user_id,
+            email: entity.email,
+            created_at: entity.created_at,
+            expires_at: entity.expires_at,
+            used_at: entity.used_at,
+        }
+    }
+}
+
+/// A single conditional update, so two concurrent clicks cannot both succeed.
+fn use_verification(
+    conn: &mut PgConnection,
+    hash: &str,
+    now: DateTime<Utc>,
+) -> Result<Option<EmailVerificationEntity>, diesel::result::Error> {
+    diesel::update(
+        email_verification_tokens
+            .filter(token_hash.eq(hash))
+            .filter(used_at.is_null())
+            .filter(expires_at.gt(now)),
+    )
+    .set(used_at.eq(now))
+    .get_result(conn)
+    .optional()
+}
+
+pub struct PostgresEmailVerificationRepository {
+    pool: ConnectionPool,
+}
+
+impl PostgresEmailVerificationRepository {
+    pub fn new(pool: ConnectionPool) -> Self {
+        PostgresEmailVerificationRepository { pool }
+    }
+}
+
+#[async_trait]
+impl EmailVerificationRepository for PostgresEmailVerificationRepository {
+    #[instrument(name = "postgres.save_verification", skip_all, fields(user_id = verification.user_id))]
+    async fn save_verification(&self, verification: &EmailVerification) -> Result<(), UserError> {
+        let entity = EmailVerificationEntity::from(verification);
+
+        run_query(&self.pool, "save_verification", move |connection| {
+            diesel::insert_into(email_verification_tokens)
+                .values(&entity)
+                .execute(connection)?;
+            Ok(())
+        })
+        .await
+    }
+
+    #[instrument(name = "postgres.use_verification", skip_all)]
+    async fn use_verification(
+        &self,
+        hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<EmailVerification>, UserError> {
+        let hash = hash.to_string();
+
+        run_query(&self.pool, "use_verification", move |connection| {
+            Ok(use_verification(connection, &hash, now)?.map(EmailVerification::from))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{env::load_enviroment, settings::Settings};
+    use crate::core::domain::secret_token::{generate_secret, hash_secret};
+    use crate::core::domain::user::NewUser;
+    use crate::core::repositories::user_repository::UserRepository;
+    use crate::infrastructure::database::postgres::database_manager::DatabaseManager;
+    use crate::infrastructure::database::postgres::postgres_user_repository::PostgresUserRepository;
+    use chrono::Duration;
+
+    fn create_pool() -> ConnectionPool {
+        load_enviroment();
+        let settings = Settings::from_env();
+        let database_manager = DatabaseManager::connect(&settings.database_config)
+            .expect("Failed to connect to the test database");
+        database_manager.get_pool()
+    }
+
+    #[tokio::test]
+    async fn test_verifications_are_single_use() {
+        let pool = create_pool();
+        let address = format!("verify-{}@email.com", uuid::Uuid::new_v4());
+        let owner = PostgresUserRepository::new(pool.clone())
+            .save_user(&NewUser::new("Owner".to_string(), address.clone()))
+            .await
+            .unwrap();
+        let repository = PostgresEmailVerificationRepository::new(pool);
+        let now = Utc::now();
+        let hash = hash_secret(&generate_secret());
+        let verification = EmailVerification {
+            token_hash: hash.clone(),
+            user_id: owner.id,
+            email: address,
+            created_at: now,
+            expires_at: now + Duration::hours(1),
+            used_at: None,
+        };
+        repository.save_verification(&verification).await.unwrap();
+
+        let used = repository.use_verification(&hash, now).await.unwrap();
+        assert_eq!(
+            used.map(|verification| verification.user_id),
+            Some(owner.id)
+        );
+        assert_eq!(repository.use_verification(&hash, now).await, Ok(None));
+    }
+}
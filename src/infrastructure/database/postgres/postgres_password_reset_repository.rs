@@ -0,0 +1,166 @@
+use crate::core::domain::password_reset::PasswordReset;
+use crate::core::errors::user_error::UserError;
+use crate::core::repositories::password_reset_repository::PasswordResetRepository;
+use crate::infrastructure::database::postgres::database_manager::ConnectionPool;
+use crate::infrastructure::database::postgres::query_runner::run_query;
+use crate::schema::password_reset_tokens::dsl::*;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::{Insertable, PgConnection, QueryDsl, Queryable, RunQueryDsl};
+use tracing::instrument;
+
+#[derive(Insertable, Queryable)]
+#[diesel(table_name = crate::schema::password_reset_tokens)]
+struct PasswordResetEntity {
+    pub token_hash: String,
+    pub user_id: i32,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+}
+
+impl From<&PasswordReset> for PasswordResetEntity {
+    fn from(reset: &PasswordReset) -> Self {
+        PasswordResetEntity {
+            token_hash: reset.token_hash.clone(),
+            user_id: reset.user_id,
+            created_at: reset.created_at,
+            expires_at: reset.expires_at,
+            used_at: reset.used_at,
+        }
+    }
+}
+
+impl From<PasswordResetEntity> for PasswordReset {
+    fn from(entity: PasswordResetEntity) -> Self {
+        PasswordReset {
+            token_hash: entity.token_hash,
+            user_id: entity.user_id,
+            created_at: entity.created_at,
+            expires_at: entity.expires_at,
+            used_at: entity.used_at,
+        }
+    }
+}
+
+/// The conditional update makes the token single-use even under concurrent
+/// requests; the second update voids any other link still in the user's inbox.
+fn use_reset(
+    conn: &mut PgConnection,
+    hash: &str,
+    now: DateTime<Utc>,
+) -> Result<Option<PasswordResetEntity>, diesel::result::Error> {
+    conn.transaction(|conn| {
+        let reset: Option<PasswordResetEntity> = diesel::update(
+            password_reset_tokens
+                .filter(token_hash.eq(hash))
+                .filter(used_at.is_null())
+                .filter(expires_at.gt(now)),
+        )
+        .set(used_at.eq(now))
+        .get_result(conn)
+        .optional()?;
+        if let Some(reset) = &reset {
+            diesel::update(
+                password_reset_tokens
+                    .filter(user_id.eq(reset.user_id))
+                    .filter(used_at.is_null()),
+            )
+            .set(used_at.eq(now))
+            .execute(conn)?;
+        }
+        Ok(reset)
+    })
+}
+
+pub struct PostgresPasswordResetRepository {
+    pool: ConnectionPool,
+}
+
+impl PostgresPasswordResetRepository {
+    pub fn new(pool: ConnectionPool) -> Self {
+        PostgresPasswordResetRepository { pool }
+    }
+}
+
+#[async_trait]
+impl PasswordResetRepository for PostgresPasswordResetRepository {
+    #[instrument(name = "postgres.save_reset", skip_all, fields(user_id = reset.user_id))]
+    async fn save_reset(&self, reset: &PasswordReset) -> Result<(), UserError> {
+        let entity = PasswordResetEntity::from(reset);
+
+        run_query(&self.pool, "save_reset", move |connection| {
+            diesel::insert_into(password_reset_tokens)
+                .values(&entity)
+                .execute(connection)?;
+            Ok(())
+        })
+        .await
+    }
+
+    #[instrument(name = "postgres.use_reset", skip_all)]
+    async fn use_reset(
+        &self,
+        hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<Option<PasswordReset>, UserError> {
+        let hash = hash.to_string();
+
+        run_query(&self.pool, "use_reset", move |connection| {
+            Ok(use_reset(connection, &hash, now)?.map(PasswordReset::from))
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{env::load_enviroment, settings::Settings};
+    use crate::core::domain::secret_token::{generate_secret, hash_secret};
+    use crate::core::domain::user::NewUser;
+    use crate::core::repositories::user_repository::UserRepository;
+    use crate::infrastructure::database::postgres::database_manager::DatabaseManager;
+    use crate::infrastructure::database::postgres::postgres_user_repository::PostgresUserRepository;
+    use chrono::Duration;
+
+    fn create_pool() -> ConnectionPool {
+        load_enviroment();
+        let settings = Settings::from_env();
+        let database_manager = DatabaseManager::connect(&settings.database_config)
+            .expect("Failed to connect to the test database");
+        database_manager.get_pool()
+    }
+
+    #[tokio::test]
+    async fn test_using_a_reset_voids_the_others() {
+        let pool = create_pool();
+        let address = format!("reset-{}@email.com", uuid::Uuid::new_v4());
+        let owner = PostgresUserRepository::new(pool.clone())
+            .save_user(&NewUser::new("Owner".to_string(), address))
+            .await
+            .unwrap();
+        let repository = PostgresPasswordResetRepository::new(pool);
+        let now = Utc::now();
+        let hashes = [
+            hash_secret(&generate_secret()),
+            hash_secret(&generate_secret()),
+        ];
+        for hash in &hashes {
+            let reset = PasswordReset {
+                token_hash: hash.clone(),
+                user_id: owner.id,
+                created_at: now,
+                expires_at: now + Duration::hours(1),
+                used_at: None,
+            };
+            repository.save_reset(&reset).await.unwrap();
+        }
+
+        let used = repository.use_reset(&hashes[1], now).await.unwrap();
+        assert_eq!(used.map(|reset| reset.user_id), Some(owner.id));
+        assert_eq!(repository.use_reset(&hashes[1], now).await, Ok(None));
+        assert_eq!(repository.use_reset(&hashes[0], now).await, Ok(None));
+    }
+}
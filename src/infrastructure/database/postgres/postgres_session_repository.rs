@@ -0,0 +1,323 @@
+use crate::core::domain::session::Session;
+use crate::core::errors::user_error::UserError;
+use crate::core::repositories::session_repository::SessionRepository;
+use crate::infrastructure::database::postgres::database_manager::ConnectionPool;
+use crate::infrastructure::database::postgres::query_runner::run_query;
+use crate::schema::sessions::dsl::*;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::prelude::*;
+use diesel::{Insertable, PgConnection, QueryDsl, Queryable, RunQueryDsl};
+use tracing::instrument;
+use uuid::Uuid;
+
+#[derive(Insertable, Queryable)]
+#[diesel(table_name = crate::schema::sessions)]
+struct SessionEntity {
+    pub id: Uuid,
+    pub user_id: i32,
+    pub refresh_token_hash: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl From<&Session> for SessionEntity {
+    fn from(session: &Session) -> Self {
+        SessionEntity {
+            id: session.id,
+            user_id: session.user_id,
+            refresh_token_hash: session.refresh_token_hash.clone(),
+            user_agent: session.user_agent.clone(),
+            ip_address: session.ip_address.clone(),
+            created_at: session.created_at,
+            last_used_at: session.last_used_at,
+            expires_at: session.expires_at,
+            revoked_at: session.revoked_at,
+        }
+    }
+}
+
+impl From<SessionEntity> for Session {
+    fn from(entity: SessionEntity) -> Self {
+        Session {
+            id: entity.id,
+            user_id: entity.user_id,
+            refresh_token_hash: entity.refresh_token_hash,
+            user_agent: entity.user_agent,
+            ip_address: entity.ip_address,
+            created_at: entity.created_at,
+            last_used_at: entity.last_used_at,
+            expires_at: entity.expires_at,
+            revoked_at: entity.revoked_at,
+        }
+    }
+}
+
+fn find_session(
+    conn: &mut PgConnection,
+    session_id: Uuid,
+) -> Result<Option<SessionEntity>, diesel::result::Error> {
+    sessions.find(session_id).first(conn).optional()
+}
+
+fn list_active_sessions(
+    conn: &mut PgConnection,
+    owner: i32,
+    now: DateTime<Utc>,
+) -> Result<Vec<SessionEntity>, diesel::result::Error> {
+    sessions
+        .filter(user_id.eq(owner))
+        .filter(revoked_at.is_null())
+        .filter(expires_at.gt(now))
+        .order(created_at.desc())
+        .load(conn)
+}
+
+fn rotate_refresh_token(
+    conn: &mut PgConnection,
+    session_id: Uuid,
+    current_hash: &str,
+    new_hash: &str,
+    now: DateTime<Utc>,
+) -> Result<usize, diesel::result::Error> {
+    diesel::update(
+        sessions
+            .filter(id.eq(session_id))
+            .filter(refresh_token_hash.eq(current_hash))
+            .filter(revoked_at.is_null()),
+    )
+    .set((refresh_token_hash.eq(new_hash), last_used_at.eq(now)))
+    .execute(conn)
+}
+
+fn revoke_session(
+    conn: &mut PgConnection,
+    owner: i32,
+    session_id: Uuid,
+    now: DateTime<Utc>,
+) -> Result<usize, diesel::result::Error> {
+    diesel::update(
+        sessions
+            .filter(id.eq(session_id))
+            .filter(user_id.eq(owner))
+            .filter(revoked_at.is_null()),
+    )
+    .set(revoked_at.eq(now))
+    .execute(conn)
+}
+
+fn revoke_user_sessions(
+    conn: &mut PgConnection,
+    owner: i32,
+    now: DateTime<Utc>,
+) -> Result<usize, diesel::result::Error> {
+    diesel::update(
+        sessions
+            .filter(user_id.eq(owner))
+            .filter(revoked_at.is_null()),
+    )
+    .set(revoked_at.eq(now))
+    .execute(conn)
+}
+
+pub struct PostgresSessionRepository {
+    pool: ConnectionPool,
+}
+
+impl PostgresSessionRepository {
+    pub fn new(pool: ConnectionPool) -> Self {
+        PostgresSessionRepository { pool }
+    }
+}
+
+#[async_trait]
+impl SessionRepository for PostgresSessionRepository {
+    #[instrument(name = "postgres.save_session", skip_all, fields(user_id = session.user_id))]
+    async fn save_session(&self, session: &Session) -> Result<(), UserError> {
+        let entity = SessionEntity::from(session);
+
+        run_query(&self.pool, "save_session", move |connection| {
+            diesel::insert_into(sessions)
+                .values(&entity)
+                .execute(connection)?;
+            Ok(())
+        })
+        .await
+    }
+
+    #[instrument(name = "postgres.find_session", skip_all)]
+    async fn find_session(&self, session_id: Uuid) -> Result<Option<Session>, UserError> {
+        run_query(&self.pool, "find_session", move |connection| {
+            Ok(find_session(connection, session_id)?.map(Session::from))
+        })
+        .await
+    }
+
+    #[instrument(name = "postgres.list_active_sessions", skip_all, fields(user_id = owner))]
+    async fn list_active_sessions(
+        &self,
+        owner: i32,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<Session>, UserError> {
+        run_query(&self.pool, "list_active_sessions", move |connection| {
+            Ok(list_active_sessions(connection, owner, now)?
+                .into_iter()
+                .map(Session::from)
+                .collect())
+        })
+        .await
+    }
+
+    #[instrument(name = "postgres.rotate_refresh_token", skip_all)]
+    async fn rotate_refresh_token(
+        &self,
+        session_id: Uuid,
+        current_hash: &str,
+        new_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<bool, UserError> {
+        let current_hash = current_hash.to_string();
+        let new_hash = new_hash.to_string();
+
+        run_query(&self.pool, "rotate_refresh_token", move |connection| {
+            let rotated =
+                rotate_refresh_token(connection, session_id, &current_hash, &new_hash, now)?;
+            Ok(rotated == 1)
+        })
+        .await
+    }
+
+    #[instrument(name = "postgres.revoke_session", skip_all, fields(user_id = owner))]
+    async fn revoke_session(
+        &self,
+        owner: i32,
+        session_id: Uuid,
+        now: DateTime<Utc>,
+    ) -> Result<bool, UserError> {
+        run_query(&self.pool, "revoke_session", move |connection| {
+            Ok(revoke_session(connection, owner, session_id, now)? == 1)
+        })
+        .await
+    }
+
+    #[instrument(name = "postgres.revoke_user_sessions", skip_all, fields(user_id = owner))]
+    async fn revoke_user_sessions(
+        &self,
+        owner: i32,
+        now: DateTime<Utc>,
+    ) -> Result<usize, UserError> {
+        run_query(&self.pool, "revoke_user_sessions", move |connection| {
+            Ok(revoke_user_sessions(connection, owner, now)?)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{env::load_enviroment, settings::Settings};
+    use crate::core::domain::user::NewUser;
+    use crate::core::repositories::user_repository::UserRepository;
+    use crate::infrastructure::database::postgres::database_manager::DatabaseManager;
+    use crate::infrastructure::database::postgres::postgres_user_repository::PostgresUserRepository;
+    use chrono::Duration;
+
+    fn create_pool() -> ConnectionPool {
+        load_enviroment();
+        let settings = Settings::from_env();
+        let database_manager = DatabaseManager::connect(&settings.database_config)
+            .expect("Failed to connect to the test database");
+        database_manager.get_pool()
+    }
+
+    /// A fresh owner per test, so tests sharing the database do not interfere.
+    async fn create_owner(pool: &ConnectionPool) -> i32 {
+        let address = format!("sessions-{}@email.com", Uuid::new_v4());
+        PostgresUserRepository::new(pool.clone())
+            .save_user(&NewUser::new("Owner".to_string(), address))
+            .await
+            .unwrap()
+            .id
+    }
+
+    fn session(owner: i32, hash: &str) -> Session {
+        let now = Utc::now();
+        Session {
+            id: Uuid::new_v4(),
+            user_id: owner,
+            refresh_token_hash: hash.to_string(),
+            user_agent: Some("curl/8.0".to_string()),
+            ip_address: Some("127.0.0.1".to_string()),
+            created_at: now,
+            last_used_at: now,
+            expires_at: now + Duration::days(1),
+            revoked_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rotation_only_succeeds_once() {
+        let pool = create_pool();
+        let repository = PostgresSessionRepository::new(pool.clone());
+        let session = session(create_owner(&pool).await, "hash-1");
+        repository.save_session(&session).await.unwrap();
+        let now = Utc::now();
+
+        assert_eq!(
+            repository
+                .rotate_refresh_token(session.id, "hash-1", "hash-2", now)
+                .await,
+            Ok(true)
+        );
+        assert_eq!(
+            repository
+                .rotate_refresh_token(session.id, "hash-1", "hash-3", now)
+                .await,
+            Ok(false)
+        );
+        let stored = repository.find_session(session.id).await.unwrap().unwrap();
+        assert_eq!(stored.refresh_token_hash, "hash-2");
+        assert_eq!(repository.find_session(Uuid::new_v4()).await, Ok(None));
+    }
+
+    #[tokio::test]
+    async fn test_revoked_sessions_are_not_listed() {
+        let pool = create_pool();
+        let repository = PostgresSessionRepository::new(pool.clone());
+        let owner = create_owner(&pool).await;
+        let first = session(owner, "hash-1");
+        let second = session(owner, "hash-2");
+        repository.save_session(&first).await.unwrap();
+        repository.save_session(&second).await.unwrap();
+        let now = Utc::now();
+
+        assert_eq!(
+            repository.revoke_session(owner + 1, first.id, now).await,
+            Ok(false)
+        );
+        assert_eq!(
+            repository.revoke_session(owner, first.id, now).await,
+            Ok(true)
+        );
+        let active = repository.list_active_sessions(owner, now).await.unwrap();
+        assert_eq!(
+            active
+                .iter()
+                .map(|session| session.id)
+                .collect::<Vec<Uuid>>(),
+            vec![second.id]
+        );
+
+        assert_eq!(repository.revoke_user_sessions(owner, now).await, Ok(1));
+        assert!(repository
+            .list_active_sessions(owner, now)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -1,59 +1,355 @@
-use crate::core::domain::user::User;
+use crate::core::domain::credentials::UserCredentials;
+use crate::core::domain::role::Role;
+use crate::core::domain::user::{NewUser, User, UserPatch};
+use crate::core::domain::user_query::{
+    page_offset, SortDirection, UserPage, UserPagination, UserQuery, UserSortField,
+};
+use crate::core::domain::user_search::{UserMatch, UserSearch};
+use crate::core::errors::user_error::UserError;
 use crate::core::repositories::user_repository::UserRepository;
 use crate::infrastructure::database::postgres::database_manager::ConnectionPool;
+use crate::infrastructure::database::postgres::query_runner::run_query;
+use crate::schema::user_roles;
 use crate::schema::users::dsl::*;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use diesel::pg::Pg;
 use diesel::prelude::*;
-use diesel::result::Error;
-use diesel::{Insertable, PgConnection, QueryDsl, Queryable, RunQueryDsl};
+use diesel::r2d2::PoolError;
+use diesel::result::{DatabaseErrorKind, Error};
+use diesel::sql_types::{BigInt, Float4, Int4, Nullable, Text, Timestamptz, Varchar};
+use diesel::{AsChangeset, Insertable, PgConnection, QueryDsl, Queryable, RunQueryDsl};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tracing::instrument;
 
 #[derive(Insertable, Deserialize)]
 #[diesel(table_name = crate::schema::users)]
-struct NewUser {
-    pub id: i32,
+struct NewUserEntity {
+    pub name: String,
+    pub email: String,
+    pub password_hash: Option<String>,
+}
+
+#[derive(AsChangeset)]
+#[diesel(table_name = crate::schema::users)]
+struct UserChangeset {
     pub name: String,
     pub email: String,
 }
 
+#[derive(AsChangeset)]
+#[diesel(table_name = crate::schema::users)]
+struct UserPatchChangeset {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
 #[derive(Debug, Queryable, Serialize, Deserialize)]
 struct UserEntity {
     pub id: i32,
     pub username: String,
     pub email: String,
+    #[serde(skip_serializing)]
+    pub password_hash: Option<String>,
+    pub email_verified_at: Option<DateTime<Utc>>,
+}
+
+define_sql_function!(fn lower(value: Varchar) -> Varchar);
+
+#[derive(Insertable)]
+#[diesel(table_name = crate::schema::user_roles)]
+struct UserRoleEntity {
+    pub user_id: i32,
+    pub role: String,
+}
+
+fn create_user(
+    conn: &mut PgConnection,
+    new_user: NewUserEntity,
+    roles: &[Role],
+) -> Result<UserEntity, Error> {
+    conn.transaction(|conn| {
+        let user: UserEntity = diesel::insert_into(users)
+            .values(&new_user)
+            .get_result(conn)?;
+        let grants = roles
+            .iter()
+            .map(|role| UserRoleEntity {
+                user_id: user.id,
+                role: role.as_str().to_string(),
+            })
+            .collect::<Vec<UserRoleEntity>>();
+        diesel::insert_into(user_roles::table)
+            .values(&grants)
+            .execute(conn)?;
+        Ok(user)
+    })
+}
+
+/// Roles granted to each of `user_ids`; names unknown to `Role` are skipped.
+fn get_roles(conn: &mut PgConnection, user_ids: &[i32]) -> Result<HashMap<i32, Vec<Role>>, Error> {
+    let grants = user_roles::table
+        .filter(user_roles::user_id.eq_any(user_ids))
+        .select((user_roles::user_id, user_roles::role))
+        .load::<(i32, String)>(conn)?;
+    let mut roles: HashMap<i32, Vec<Role>> = HashMap::new();
+    for (owner, role_name) in grants {
+        if let Some(role) = Role::parse(&role_name) {
+            roles.entry(owner).or_default().push(role);
+        }
+    }
+    roles.values_mut().for_each(|roles| roles.sort());
+    Ok(roles)
+}
+
+fn with_roles(conn: &mut PgConnection, entities: Vec<UserEntity>) -> Result<Vec<User>, Error> {
+    let user_ids = entities.iter().map(|user| user.id).collect::<Vec<i32>>();
+    let mut roles = get_roles(conn, &user_ids)?;
+    Ok(entities
+        .into_iter()
+        .map(|user| {
+            let granted = roles.remove(&user.id).unwrap_or_default();
+            User::new(user.id, user.username, user.email)
+                .with_roles(granted)
+                .with_email_verified_at(user.email_verified_at)
+        })
+        .collect())
+}
+
+fn into_user(conn: &mut PgConnection, entity: UserEntity) -> Result<User, Error> {
+    Ok(with_roles(conn, vec![entity])?.remove(0))
+}
+
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+fn filtered_users(query: &UserQuery) -> crate::schema::users::BoxedQuery<'static, Pg> {
+    let mut statement = users.into_boxed();
+    if let Some(domain) = &query.email_domain {
+        statement = statement.filter(email.ilike(format!("%@{}", escape_like(domain))));
+    }
+    if let Some(fragment) = &query.name_contains {
+        statement = statement.filter(name.ilike(format!("%{}%", escape_like(fragment))));
+    }
+    statement
+}
+
+fn get_users(
+    conn: &mut PgConnection,
+    query: &UserQuery,
+) -> Result<(Vec<UserEntity>, i64), UserError> {
+    let total = filtered_users(query).count().get_result(conn)?;
+
+    let statement = match (query.sort, query.direction) {
+        (UserSortField::Id, SortDirection::Asc) => filtered_users(query).order_by(id.asc()),
+        (UserSortField::Id, SortDirection::Desc) => filtered_users(query).order_by(id.desc()),
+        (UserSortField::Name, SortDirection::Asc) => filtered_users(query)
+            .order_by(name.asc())
+            .then_order_by(id.asc()),
+        (UserSortField::Name, SortDirection::Desc) => filtered_users(query)
+            .order_by(name.desc())
+            .then_order_by(id.desc()),
+        (UserSortField::Email, SortDirection::Asc) => filtered_users(query)
+            .order_by(email.asc())
+            .then_order_by(id.asc()),
+        (UserSortField::Email, SortDirection::Desc) => filtered_users(query)
+            .order_by(email.desc())
+            .then_order_by(id.desc()),
+    };
+
+    let statement = match query.pagination {
+        UserPagination::Cursor { after, limit } => {
+            let statement = match (after, query.direction) {
+                (None, _) => statement,
+                (Some(after), SortDirection::Asc) => statement.filter(id.gt(after)),
+                (Some(after), SortDirection::Desc) => statement.filter(id.lt(after)),
+            };
+            statement.limit(limit + 1)
+        }
+        UserPagination::Page { page, per_page } => {
+            let offset =
+                page_offset(page, per_page).map_err(|error| UserError::Validation(vec![error]))?;
+            statement.offset(offset).limit(per_page)
+        }
+    };
+
+    Ok((statement.load::<UserEntity>(conn)?, total))
 }
 
-fn create_user(conn: &mut PgConnection, new_user: NewUser) -> Result<UserEntity, Error> {
-    diesel::insert_into(users)
-        .values(&new_user)
-        .get_result(conn)
+#[derive(Debug, QueryableByName)]
+struct UserMatchEntity {
+    #[diesel(sql_type = Int4, column_name = id)]
+    pub user_id: i32,
+    #[diesel(sql_type = Varchar, column_name = name)]
+    pub username: String,
+    #[diesel(sql_type = Varchar, column_name = email)]
+    pub user_email: String,
+    #[diesel(sql_type = Nullable<Timestamptz>, column_name = email_verified_at)]
+    pub verified_at: Option<DateTime<Utc>>,
+    #[diesel(sql_type = Float4)]
+    pub score: f32,
 }
 
-fn get_users(conn: &mut PgConnection) -> Result<Vec<UserEntity>, Error> {
-    users.load::<UserEntity>(conn)
+fn search_users(
+    conn: &mut PgConnection,
+    search: &UserSearch,
+) -> Result<Vec<UserMatchEntity>, Error> {
+    let query = r#"
+        SELECT id, name, email, email_verified_at,
+               GREATEST(
+                   similarity(name, $1),
+                   similarity(email, $1),
+                   CASE WHEN name ILIKE $2 OR email ILIKE $2 THEN 0.5 ELSE 0 END
+               )::real AS score
+        FROM users
+        WHERE name % $1 OR email % $1 OR name ILIKE $2 OR email ILIKE $2
+        ORDER BY score DESC, id ASC
+        LIMIT $3
+    "#;
+    diesel::sql_query(query)
+        .bind::<Text, _>(&search.term)
+        .bind::<Text, _>(format!("%{}%", escape_like(&search.term)))
+        .bind::<BigInt, _>(search.limit)
+        .load(conn)
 }
 
 fn get_user_by_id(conn: &mut PgConnection, user_id: i32) -> Result<UserEntity, Error> {
     users.filter(id.eq(user_id)).first(conn)
 }
 
-fn delete_user(conn: &mut PgConnection, user_id: i32) -> Result<usize, Error> {
-    diesel::delete(users.filter(id.eq(user_id))).execute(conn)
+fn find_user_by_email(conn: &mut PgConnection, address: &str) -> Result<Option<UserEntity>, Error> {
+    users
+        .filter(lower(email).eq(lower(address)))
+        .first(conn)
+        .optional()
 }
 
-fn get_last_user(conn: &mut PgConnection) -> Option<UserEntity> {
-    users.order(id.desc()).first(conn).ok()
+fn get_password_hash(conn: &mut PgConnection, user_id: i32) -> Result<Option<String>, Error> {
+    users
+        .filter(id.eq(user_id))
+        .select(password_hash)
+        .first(conn)
 }
 
-fn update_user_email(
+fn set_password_hash(conn: &mut PgConnection, user_id: i32, hash: &str) -> Result<usize, Error> {
+    diesel::update(users.filter(id.eq(user_id)))
+        .set(password_hash.eq(hash))
+        .execute(conn)
+}
+
+/// The roles cascade away with the row, so they are read first; the
+/// `DELETE ... RETURNING` alone decides whether the user existed.
+fn delete_user(conn: &mut PgConnection, user_id: i32) -> Result<User, Error> {
+    conn.transaction(|conn| {
+        let granted = get_roles(conn, &[user_id])?
+            .remove(&user_id)
+            .unwrap_or_default();
+        let user: UserEntity = diesel::delete(users.filter(id.eq(user_id))).get_result(conn)?;
+        Ok(User::new(user.id, user.username, user.email)
+            .with_roles(granted)
+            .with_email_verified_at(user.email_verified_at))
+    })
+}
+
+/// Runs `change` and clears the verification if it gave the user a new email.
+fn change_user<F>(conn: &mut PgConnection, user_id: i32, change: F) -> Result<UserEntity, Error>
+where
+    F: FnOnce(&mut PgConnection) -> Result<UserEntity, Error>,
+{
+    conn.transaction(|conn| {
+        let previous_email: String = users.find(user_id).select(email).first(conn)?;
+        let user = change(conn)?;
+        if user.email == previous_email || user.email_verified_at.is_none() {
+            return Ok(user);
+        }
+        diesel::update(users.find(user_id))
+            .set(email_verified_at.eq(None::<DateTime<Utc>>))
+            .get_result(conn)
+    })
+}
+
+fn update_user(
+    conn: &mut PgConnection,
+    user_id: i32,
+    changeset: UserChangeset,
+) -> Result<UserEntity, Error> {
+    change_user(conn, user_id, |conn| {
+        diesel::update(users.filter(id.eq(user_id)))
+            .set(&changeset)
+            .get_result(conn)
+    })
+}
+
+fn patch_user(
+    conn: &mut PgConnection,
+    user_id: i32,
+    changeset: UserPatchChangeset,
+) -> Result<UserEntity, Error> {
+    change_user(conn, user_id, |conn| {
+        diesel::update(users.filter(id.eq(user_id)))
+            .set(&changeset)
+            .get_result(conn)
+    })
+}
+
+fn grant_role(conn: &mut PgConnection, user_id: i32, granted: Role) -> Result<User, Error> {
+    conn.transaction(|conn| {
+        let user = get_user_by_id(conn, user_id)?;
+        diesel::insert_into(user_roles::table)
+            .values(UserRoleEntity {
+                user_id,
+                role: granted.as_str().to_string(),
+            })
+            .on_conflict_do_nothing()
+            .execute(conn)?;
+        into_user(conn, user)
+    })
+}
+
+fn mark_email_verified(
     conn: &mut PgConnection,
     user_id: i32,
-    new_email: String,
+    address: &str,
+    verified_at: DateTime<Utc>,
 ) -> Result<usize, Error> {
-    diesel::update(users.filter(id.eq(user_id)))
-        .set(email.eq(new_email))
+    diesel::update(users.filter(id.eq(user_id)).filter(email.eq(address)))
+        .set(email_verified_at.eq(verified_at))
         .execute(conn)
 }
 
+impl From<Error> for UserError {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::NotFound => UserError::NotFound,
+            Error::DatabaseError(DatabaseErrorKind::UniqueViolation, info)
+                if info
+                    .constraint_name()
+                    .is_some_and(|constraint| constraint.starts_with("users_email")) =>
+            {
+                UserError::email_taken()
+            }
+            Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                UserError::Conflict("User already exists".to_string())
+            }
+            Error::DatabaseError(DatabaseErrorKind::ClosedConnection, info) => {
+                UserError::Unavailable(info.message().to_string())
+            }
+            error => UserError::Storage(error.to_string()),
+        }
+    }
+}
+
+impl From<PoolError> for UserError {
+    fn from(error: PoolError) -> Self {
+        UserError::Unavailable(format!("Error getting connection: {}", error))
+    }
+}
+
 pub struct PostgresUserRepository {
     pool: ConnectionPool,
 }
@@ -62,105 +358,217 @@ impl PostgresUserRepository {
     pub fn new(pool: ConnectionPool) -> Self {
         PostgresUserRepository { pool }
     }
+
+    async fn run<T, F>(&self, operation: &'static str, query: F) -> Result<T, UserError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut PgConnection) -> Result<T, UserError> + Send + 'static,
+    {
+        run_query(&self.pool, operation, query).await
+    }
 }
 
+#[async_trait]
 impl UserRepository for PostgresUserRepository {
-    fn save_user(&self, user: &User) -> Result<User, String> {
-        let new_user = NewUser {
-            id: user.id,
+    #[instrument(name = "postgres.save_user", skip_all)]
+    async fn save_user(&self, user: &NewUser) -> Result<User, UserError> {
+        let new_user = NewUserEntity {
             name: user.name.clone(),
             email: user.email.clone(),
+            password_hash: user.password_hash.clone(),
         };
 
-        let mut connection = self
-            .pool
-            .get()
-            .map_err(|e| format!("Error getting connection: {}", e))?;
+        let roles = user.roles.clone();
 
-        match create_user(&mut connection, new_user) {
-            Ok(user) => Ok(User::new(user.id, user.username, user.email)),
-            Err(e) => Err(format!("Error saving user: {}", e)),
-        }
+        self.run("save_user", move |connection| {
+            let user = create_user(connection, new_user, &roles)?;
+            Ok(User::new(user.id, user.username, user.email).with_roles(roles))
+        })
+        .await
     }
 
-    fn get_user_by_id(&self, user_id: i32) -> Option<User> {
-        let mut connection = self
-            .pool
-            .get()
-            .map_err(|e| format!("Error getting connection: {}", e))
-            .ok()?;
+    #[instrument(name = "postgres.search", skip_all)]
+    async fn search(&self, search: &UserSearch) -> Result<Vec<UserMatch>, UserError> {
+        let search = search.clone();
 
-        match get_user_by_id(&mut connection, user_id) {
-            Ok(user) => Some(User::new(user.id, user.username, user.email)),
-            Err(_) => None,
-        }
+        self.run("search", move |connection| {
+            let matches = search_users(connection, &search)?;
+            let user_ids = matches
+                .iter()
+                .map(|user| user.user_id)
+                .collect::<Vec<i32>>();
+            let mut roles = get_roles(connection, &user_ids)?;
+            Ok(matches
+                .into_iter()
+                .map(|user| UserMatch {
+                    user: User::new(user.user_id, user.username, user.user_email)
+                        .with_roles(roles.remove(&user.user_id).unwrap_or_default())
+                        .with_email_verified_at(user.verified_at),
+                    score: user.score,
+                })
+                .collect())
+        })
+        .await
+    }
+
+    #[instrument(name = "postgres.get_user_by_id", skip_all, fields(user_id = user_id))]
+    async fn get_user_by_id(&self, user_id: i32) -> Result<User, UserError> {
+        self.run("get_user_by_id", move |connection| {
+            let user = get_user_by_id(connection, user_id)?;
+            Ok(into_user(connection, user)?)
+        })
+        .await
     }
 
-    fn delete_user(&self, user_id: i32) -> Result<User, String> {
-        let mut connection = self
-            .pool
-            .get()
-            .map_err(|e| format!("Error getting connection: {}", e))?;
+    #[instrument(name = "postgres.delete_user", skip_all, fields(user_id = user_id))]
+    async fn delete_user(&self, user_id: i32) -> Result<User, UserError> {
+        self.run("delete_user", move |connection| {
+            Ok(delete_user(connection, user_id)?)
+        })
+        .await
+    }
+
+    #[instrument(name = "postgres.list_users", skip_all)]
+    async fn list_users(&self, query: &UserQuery) -> Result<UserPage, UserError> {
+        let query = query.clone();
+
+        self.run("list_users", move |connection| {
+            let (mut entities, total) = get_users(connection, &query)?;
+            let next_cursor = match query.pagination {
+                UserPagination::Cursor { limit, .. } if entities.len() as i64 > limit => {
+                    entities.truncate(limit as usize);
+                    // Only an id-sorted listing can be resumed from a cursor.
+                    entities
+                        .last()
+                        .map(|user| user.id)
+                        .filter(|_| query.sort == UserSortField::Id)
+                }
+                _ => None,
+            };
+
+            Ok(UserPage {
+                users: with_roles(connection, entities)?,
+                total,
+                next_cursor,
+            })
+        })
+        .await
+    }
 
-        let user = match get_user_by_id(&mut connection, user_id) {
-            Ok(user) => user,
-            Err(_) => return Err("User not found".to_string()),
+    #[instrument(name = "postgres.update_user", skip_all, fields(user_id = user.id))]
+    async fn update_user(&self, user: &User) -> Result<User, UserError> {
+        let user_id = user.id;
+        let changeset = UserChangeset {
+            name: user.name.clone(),
+            email: user.email.clone(),
         };
 
-        match delete_user(&mut connection, user_id) {
-            Ok(_) => Ok(User::new(user.id, user.username, user.email)),
-            Err(e) => Err(format!("Error deleting user: {}", e)),
-        }
+        self.run("update_user", move |connection| {
+            let user = update_user(connection, user_id, changeset)?;
+            Ok(into_user(connection, user)?)
+        })
+        .await
     }
 
-    fn list_users(&self) -> Vec<User> {
-        let mut connection = self
-            .pool
-            .get()
-            .map_err(|e| format!("Error getting connection: {}", e))
-            .unwrap();
+    #[instrument(name = "postgres.patch_user", skip_all, fields(user_id = user_id))]
+    async fn patch_user(&self, user_id: i32, patch: &UserPatch) -> Result<User, UserError> {
+        let changeset = if patch.is_empty() {
+            None
+        } else {
+            Some(UserPatchChangeset {
+                name: patch.name.clone(),
+                email: patch.email.clone(),
+            })
+        };
 
-        get_users(&mut connection)
-            .unwrap()
-            .into_iter()
-            .map(|user| User::new(user.id, user.username, user.email))
-            .collect()
+        self.run("patch_user", move |connection| {
+            let user = match changeset {
+                None => get_user_by_id(connection, user_id)?,
+                Some(changeset) => patch_user(connection, user_id, changeset)?,
+            };
+            Ok(into_user(connection, user)?)
+        })
+        .await
     }
 
-    fn get_last_user(&self) -> Option<User> {
-        let mut connection = self
-            .pool
-            .get()
-            .map_err(|e| format!("Error getting connection: {}", e))
-            .unwrap();
+    #[instrument(name = "postgres.grant_role", skip_all, fields(user_id = user_id))]
+    async fn grant_role(&self, user_id: i32, granted: Role) -> Result<User, UserError> {
+        self.run("grant_role", move |connection| {
+            Ok(grant_role(connection, user_id, granted)?)
+        })
+        .await
+    }
 
-        get_last_user(&mut connection).map(|user| User::new(user.id, user.username, user.email))
+    #[instrument(name = "postgres.find_credentials_by_email", skip_all)]
+    async fn find_credentials_by_email(
+        &self,
+        address: &str,
+    ) -> Result<Option<UserCredentials>, UserError> {
+        let address = address.to_string();
+
+        self.run("find_credentials_by_email", move |connection| {
+            let Some(user) = find_user_by_email(connection, &address)? else {
+                return Ok(None);
+            };
+            let hash = user.password_hash.clone();
+            Ok(Some(UserCredentials {
+                user: into_user(connection, user)?,
+                password_hash: hash,
+            }))
+        })
+        .await
     }
 
-    fn update_user(&self, user: &User) -> Result<User, String> {
-        let mut connection = self
-            .pool
-            .get()
-            .map_err(|e| format!("Error getting connection: {}", e))?;
+    #[instrument(name = "postgres.get_password_hash", skip_all, fields(user_id = user_id))]
+    async fn get_password_hash(&self, user_id: i32) -> Result<Option<String>, UserError> {
+        self.run("get_password_hash", move |connection| {
+            Ok(get_password_hash(connection, user_id)?)
+        })
+        .await
+    }
 
-        match update_user_email(&mut connection, user.id, user.email.clone()) {
-            Ok(_) => Ok(User::new(user.id, user.name.clone(), user.email.clone())),
-            Err(e) => Err(format!("Error updating user: {}", e)),
-        }
+    #[instrument(name = "postgres.set_password_hash", skip_all, fields(user_id = user_id))]
+    async fn set_password_hash(&self, user_id: i32, hash: &str) -> Result<(), UserError> {
+        let hash = hash.to_string();
+
+        self.run(
+            "set_password_hash",
+            move |connection| match set_password_hash(connection, user_id, &hash)? {
+                0 => Err(UserError::NotFound),
+                _ => Ok(()),
+            },
+        )
+        .await
+    }
+
+    #[instrument(name = "postgres.mark_email_verified", skip_all, fields(user_id = user_id))]
+    async fn mark_email_verified(
+        &self,
+        user_id: i32,
+        address: &str,
+        verified_at: DateTime<Utc>,
+    ) -> Result<bool, UserError> {
+        let address = address.to_string();
+
+        self.run("mark_email_verified", move |connection| {
+            if mark_email_verified(connection, user_id, &address, verified_at)? == 1 {
+                return Ok(true);
+            }
+            // Tell a changed email apart from a missing user.
+            get_user_by_id(connection, user_id)?;
+            Ok(false)
+        })
+        .await
     }
 
     #[cfg(test)]
-    fn drop_database(&self) -> Result<(), String> {
-        let mut connection = self
-            .pool
-            .get()
-            .map_err(|e| format!("Error getting connection: {}", e))?;
-
-        let query = r#"TRUNCATE TABLE users CASCADE"#;
-        match diesel::sql_query(query).execute(&mut connection) {
-            Ok(_) => Ok(()),
-            Err(e) => Err(format!("Error dropping table: {}", e)),
-        }
+    async fn drop_database(&self) -> Result<(), UserError> {
+        self.run("drop_database", |connection| {
+            let query = r#"TRUNCATE TABLE users RESTART IDENTITY CASCADE"#;
+            diesel::sql_query(query).execute(connection)?;
+            Ok(())
+        })
+        .await
     }
 }
 
@@ -172,72 +580,446 @@ mod tests {
 
     fn create_pool() -> ConnectionPool {
         load_enviroment();
-        let settings = Settings::new();
-        let database_manager = DatabaseManager::new(&format!(
-            "postgres://{}:{}@{}/{}",
-            settings.database_config.user,
-            settings.database_config.password,
-            settings.database_config.host,
-            settings.database_config.database
-        ));
+        let settings = Settings::from_env();
+        let database_manager = DatabaseManager::connect(&settings.database_config)
+            .expect("Failed to connect to the test database");
         database_manager.get_pool()
     }
 
-    #[test]
-    fn test_save_user() {
+    fn new_user(user_name: &str, user_email: &str) -> NewUser {
+        NewUser::new(user_name.to_string(), user_email.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_save_user() {
         let repository = PostgresUserRepository::new(create_pool());
-        repository.drop_database().unwrap();
+        repository.drop_database().await.unwrap();
         let user = User::new(1, "John".to_string(), "john@email.com".to_string());
-        assert_eq!(repository.save_user(&user), Ok(user));
+        assert_eq!(
+            repository
+                .save_user(&new_user("John", "john@email.com"))
+                .await,
+            Ok(user)
+        );
     }
 
-    #[test]
-    fn test_get_user_by_id() {
+    #[tokio::test]
+    async fn test_save_user_assigns_sequential_ids() {
         let repository = PostgresUserRepository::new(create_pool());
-        repository.drop_database().unwrap();
-        let user = User::new(1, "John".to_string(), "john@email.com".to_string());
-        repository.save_user(&user).unwrap();
-        assert_eq!(repository.get_user_by_id(1), Some(user));
+        repository.drop_database().await.unwrap();
+        let user1 = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        let user2 = repository
+            .save_user(&new_user("Jane", "jane@email.com"))
+            .await
+            .unwrap();
+        assert_eq!((user1.id, user2.id), (1, 2));
     }
 
-    #[test]
-    fn test_delete_user() {
+    #[tokio::test]
+    async fn test_get_user_by_id() {
         let repository = PostgresUserRepository::new(create_pool());
-        repository.drop_database().unwrap();
-        let user = User::new(1, "John".to_string(), "john@email.com".to_string());
-        repository.save_user(&user).unwrap();
-        assert_eq!(repository.delete_user(1), Ok(user));
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        assert_eq!(repository.get_user_by_id(user.id).await, Ok(user));
+    }
+
+    #[tokio::test]
+    async fn test_delete_user() {
+        let repository = PostgresUserRepository::new(create_pool());
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        let deleted = repository.delete_user(user.id).await.unwrap();
+        assert_eq!(deleted.roles, vec![Role::User]);
+        assert_eq!(deleted, user);
+        assert_eq!(
+            repository.delete_user(user.id).await,
+            Err(UserError::NotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_users() {
+        let repository = PostgresUserRepository::new(create_pool());
+        repository.drop_database().await.unwrap();
+        let user1 = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        let user2 = repository
+            .save_user(&new_user("Jane", "jane@email.com"))
+            .await
+            .unwrap();
+        assert_eq!(
+            repository
+                .list_users(&UserQuery::default())
+                .await
+                .map(|page| page.users),
+            Ok(vec![user1, user2])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_users_with_cursor() {
+        let repository = PostgresUserRepository::new(create_pool());
+        repository.drop_database().await.unwrap();
+        for (user_name, user_email) in [
+            ("John", "john@email.com"),
+            ("Jane", "jane@email.com"),
+            ("Jack", "jack@email.com"),
+        ] {
+            repository
+                .save_user(&new_user(user_name, user_email))
+                .await
+                .unwrap();
+        }
+        let mut query = UserQuery {
+            pagination: UserPagination::Cursor {
+                after: None,
+                limit: 2,
+            },
+            ..UserQuery::default()
+        };
+        let first = repository.list_users(&query).await.unwrap();
+        assert_eq!(first.total, 3);
+        assert_eq!(first.users.len(), 2);
+        assert_eq!(first.next_cursor, Some(2));
+
+        query.pagination = UserPagination::Cursor {
+            after: first.next_cursor,
+            limit: 2,
+        };
+        let second = repository.list_users(&query).await.unwrap();
+        assert_eq!(
+            second
+                .users
+                .iter()
+                .map(|user| user.id)
+                .collect::<Vec<i32>>(),
+            vec![3]
+        );
+        assert_eq!(second.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn test_list_users_rejects_overflowing_pages() {
+        let repository = PostgresUserRepository::new(create_pool());
+        let query = UserQuery {
+            pagination: UserPagination::Page {
+                page: i64::MAX,
+                per_page: 100,
+            },
+            ..UserQuery::default()
+        };
+        assert!(matches!(
+            repository.list_users(&query).await,
+            Err(UserError::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_users_filtered_and_sorted() {
+        let repository = PostgresUserRepository::new(create_pool());
+        repository.drop_database().await.unwrap();
+        for (user_name, user_email) in [
+            ("John", "john@email.com"),
+            ("Jane", "jane@example.com"),
+            ("Johanna", "johanna@EMAIL.com"),
+        ] {
+            repository
+                .save_user(&new_user(user_name, user_email))
+                .await
+                .unwrap();
+        }
+        let query = UserQuery {
+            pagination: UserPagination::Page {
+                page: 1,
+                per_page: 10,
+            },
+            sort: UserSortField::Name,
+            direction: SortDirection::Desc,
+            email_domain: Some("email.com".to_string()),
+            name_contains: Some("jo".to_string()),
+        };
+        let page = repository.list_users(&query).await.unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(
+            page.users
+                .iter()
+                .map(|user| user.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["John", "Johanna"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_search_ranks_closer_matches_first() {
+        let repository = PostgresUserRepository::new(create_pool());
+        repository.drop_database().await.unwrap();
+        for (user_name, user_email) in [
+            ("Maryjohn", "mary@email.com"),
+            ("Jane", "jane@email.com"),
+            ("John", "john@email.com"),
+        ] {
+            repository
+                .save_user(&new_user(user_name, user_email))
+                .await
+                .unwrap();
+        }
+        let matches = repository
+            .search(&UserSearch::new("JOHN", None))
+            .await
+            .unwrap();
+        assert_eq!(
+            matches
+                .iter()
+                .map(|user_match| user_match.user.name.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["John", "Maryjohn"]
+        );
+        assert!(matches[0].score > matches[1].score);
+    }
+
+    #[tokio::test]
+    async fn test_update_user() {
+        let repository = PostgresUserRepository::new(create_pool());
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        let updated_user = User::new(
+            user.id,
+            "John Doe".to_string(),
+            "john-doe@email.com".to_string(),
+        );
+        assert_eq!(
+            repository.update_user(&updated_user).await,
+            Ok(updated_user)
+        );
+        assert_eq!(
+            repository
+                .get_user_by_id(user.id)
+                .await
+                .map(|user| user.name),
+            Ok("John Doe".to_string())
+        );
     }
 
-    #[test]
-    fn test_list_users() {
+    #[tokio::test]
+    async fn test_roles_are_kept_across_updates() {
         let repository = PostgresUserRepository::new(create_pool());
-        repository.drop_database().unwrap();
-        let user1 = User::new(1, "John".to_string(), "john@email.com".to_string());
-        let user2 = User::new(2, "Jane".to_string(), "jane@email.com".to_string());
-        repository.save_user(&user1).unwrap();
-        repository.save_user(&user2).unwrap();
-        assert_eq!(repository.list_users(), vec![user1, user2]);
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        assert_eq!(user.roles, vec![Role::User]);
+        let updated_user = User::new(user.id, "John Doe".to_string(), user.email.clone());
+        assert_eq!(
+            repository
+                .update_user(&updated_user)
+                .await
+                .map(|user| user.roles),
+            Ok(vec![Role::User])
+        );
+        assert_eq!(
+            repository
+                .get_user_by_id(user.id)
+                .await
+                .map(|user| user.roles),
+            Ok(vec![Role::User])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_grant_role() {
+        let repository = PostgresUserRepository::new(create_pool());
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        for _ in 0..2 {
+            assert_eq!(
+                repository
+                    .grant_role(user.id, Role::Admin)
+                    .await
+                    .map(|user| user.roles),
+                Ok(vec![Role::Admin, Role::User])
+            );
+        }
+        assert_eq!(
+            repository.grant_role(user.id + 1, Role::Admin).await,
+            Err(UserError::NotFound)
+        );
     }
 
-    #[test]
-    fn test_get_last_user_id() {
+    #[tokio::test]
+    async fn test_changing_the_email_clears_its_verification() {
         let repository = PostgresUserRepository::new(create_pool());
-        repository.drop_database().unwrap();
-        let user1 = User::new(1, "John".to_string(), "john@email.com".to_string());
-        let user2 = User::new(2, "Jane".to_string(), "jane@email.com".to_string());
-        repository.save_user(&user1).unwrap();
-        repository.save_user(&user2).unwrap();
-        assert_eq!(repository.get_last_user().unwrap(), user2);
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        assert_eq!(user.email_verified_at, None);
+        let now = chrono::Utc::now();
+        assert_eq!(
+            repository
+                .mark_email_verified(user.id, "old@email.com", now)
+                .await,
+            Ok(false)
+        );
+        assert_eq!(
+            repository
+                .mark_email_verified(user.id, "john@email.com", now)
+                .await,
+            Ok(true)
+        );
+
+        let renamed = UserPatch::new(Some("John Doe".to_string()), None);
+        let user = repository.patch_user(user.id, &renamed).await.unwrap();
+        assert!(user.email_verified_at.is_some());
+
+        let moved = UserPatch::new(None, Some("john.doe@email.com".to_string()));
+        let user = repository.patch_user(user.id, &moved).await.unwrap();
+        assert_eq!(user.email_verified_at, None);
     }
 
-    #[test]
-    fn test_update_user() {
+    #[tokio::test]
+    async fn test_password_hashes() {
         let repository = PostgresUserRepository::new(create_pool());
-        repository.drop_database().unwrap();
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com").with_password_hash("hash-1".to_string()))
+            .await
+            .unwrap();
+        let credentials = repository
+            .find_credentials_by_email("JOHN@email.com")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(credentials.user.id, user.id);
+        assert_eq!(credentials.user.roles, vec![Role::User]);
+        assert_eq!(credentials.password_hash.as_deref(), Some("hash-1"));
+
+        repository
+            .set_password_hash(user.id, "hash-2")
+            .await
+            .unwrap();
+        assert_eq!(
+            repository.get_password_hash(user.id).await,
+            Ok(Some("hash-2".to_string()))
+        );
+        assert_eq!(
+            repository.find_credentials_by_email("jane@email.com").await,
+            Ok(None)
+        );
+        assert_eq!(
+            repository.set_password_hash(user.id + 1, "hash-3").await,
+            Err(UserError::NotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_patch_user() {
+        let repository = PostgresUserRepository::new(create_pool());
+        repository.drop_database().await.unwrap();
+        let user = repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        let patch = UserPatch::new(None, Some("john-doe@email.com".to_string()));
+        assert_eq!(
+            repository.patch_user(user.id, &patch).await,
+            Ok(User::new(
+                user.id,
+                "John".to_string(),
+                "john-doe@email.com".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_patch_missing_user_is_not_found() {
+        let repository = PostgresUserRepository::new(create_pool());
+        repository.drop_database().await.unwrap();
+        assert_eq!(
+            repository.patch_user(1, &UserPatch::default()).await,
+            Err(UserError::NotFound)
+        );
+        let patch = UserPatch::new(Some("John".to_string()), None);
+        assert_eq!(
+            repository.patch_user(1, &patch).await,
+            Err(UserError::NotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_missing_user_is_not_found() {
+        let repository = PostgresUserRepository::new(create_pool());
+        repository.drop_database().await.unwrap();
         let user = User::new(1, "John".to_string(), "john@email.com".to_string());
-        repository.save_user(&user).unwrap();
-        let updated_user = User::new(1, "John Doe".to_string(), "john-doe@email.com".to_string());
-        assert_eq!(repository.update_user(&updated_user), Ok(updated_user));
+        assert_eq!(
+            repository.update_user(&user).await,
+            Err(UserError::NotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_save_duplicate_email_is_conflict() {
+        let repository = PostgresUserRepository::new(create_pool());
+        repository.drop_database().await.unwrap();
+        repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        assert_eq!(
+            repository
+                .save_user(&new_user("Johnny", "John@Email.com"))
+                .await,
+            Err(UserError::email_taken())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_to_taken_email_is_conflict() {
+        let repository = PostgresUserRepository::new(create_pool());
+        repository.drop_database().await.unwrap();
+        repository
+            .save_user(&new_user("John", "john@email.com"))
+            .await
+            .unwrap();
+        let jane = repository
+            .save_user(&new_user("Jane", "jane@email.com"))
+            .await
+            .unwrap();
+        let patch = UserPatch::new(None, Some("JOHN@email.com".to_string()));
+        assert_eq!(
+            repository.patch_user(jane.id, &patch).await,
+            Err(UserError::email_taken())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_queries_do_not_block_the_runtime() {
+        let repository = PostgresUserRepository::new(create_pool());
+        let slow_query = repository.run("sleep", |connection| {
+            diesel::sql_query("SELECT pg_sleep(0.5)").execute(connection)?;
+            Ok(())
+        });
+        tokio::select! {
+            _ = slow_query => panic!("query should still be running"),
+            _ = tokio::time::sleep(std::time::Duration::from_millis(50)) => {}
+        }
     }
 }
@@ -0,0 +1,42 @@
+use crate::core::errors::user_error::UserError;
+use crate::infrastructure::database::postgres::database_manager::ConnectionPool;
+use crate::infrastructure::metrics::METRICS;
+use diesel::PgConnection;
+use std::time::Instant;
+use tracing::Span;
+
+/// Diesel is synchronous, so every query runs on tokio's blocking pool
+/// instead of the actix worker that awaits it. `operation` labels its timing.
+pub async fn run_query<T, F>(
+    pool: &ConnectionPool,
+    operation: &'static str,
+    query: F,
+) -> Result<T, UserError>
+where
+    T: Send + 'static,
+    F: FnOnce(&mut PgConnection) -> Result<T, UserError> + Send + 'static,
+{
+    let pool = pool.clone();
+    let span = Span::current();
+    let started = Instant::now();
+    let result = tokio::task::spawn_blocking(move || {
+        let _entered = span.enter();
+        let checkout = Instant::now();
+        let connection = pool.get();
+        METRICS.observe_pool_wait(checkout.elapsed());
+        let mut connection = connection?;
+        query(&mut connection)
+    })
+    .await
+    .map_err(|error| UserError::Storage(format!("Database task failed: {}", error)))
+    .and_then(|result| result);
+
+    let outcome = match &result {
+        Ok(_) => "ok",
+        Err(UserError::NotFound) => "not_found",
+        Err(UserError::Conflict(_)) => "conflict",
+        Err(_) => "error",
+    };
+    METRICS.observe_repository(operation, outcome, started.elapsed());
+    result
+}
@@ -0,0 +1,440 @@
+use crate::config::auth_config::AuthConfig;
+use crate::core::domain::caller::Caller;
+use crate::core::domain::role::Role;
+use crate::core::domain::user::User;
+use crate::core::errors::auth_error::AuthError;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::token_issuer::{AccessToken, TokenIssuer};
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::jwk::{AlgorithmParameters, JwkSet, KeyAlgorithm};
+use jsonwebtoken::{
+    decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Deserialize)]
+struct Claims {
+    sub: String,
+    /// Role names; ones this service does not know are ignored.
+    #[serde(default)]
+    roles: Vec<String>,
+}
+
+struct RsaKey {
+    kid: Option<String>,
+    key: DecodingKey,
+}
+
+/// Verifies bearer tokens: HS256 against the configured secret and RS256
+/// against the keys of a local JWKS file. A token's `alg` only selects among
+/// the configured key types, so an RS256 public key is never used as an
+/// HS256 secret.
+pub struct JwtVerifier {
+    secret: Option<DecodingKey>,
+    rsa_keys: Vec<RsaKey>,
+    issuer: String,
+    audience: String,
+    leeway: u64,
+}
+
+impl JwtVerifier {
+    pub fn from_config(config: &AuthConfig) -> Result<Self, String> {
+        let rsa_keys = match &config.jwks_file {
+            None => Vec::new(),
+            Some(path) => {
+                let contents = fs::read_to_string(path).map_err(|error| {
+                    format!("Cannot read JWKS from {}: {}", path.display(), error)
+                })?;
+                load_rsa_keys(&contents)
+                    .map_err(|error| format!("Invalid JWKS in {}: {}", path.display(), error))?
+            }
+        };
+
+        Ok(JwtVerifier {
+            secret: config
+                .jwt_secret
+                .as_ref()
+                .map(|secret| DecodingKey::from_secret(secret.as_bytes())),
+            rsa_keys,
+            issuer: config.issuer.clone(),
+            audience: config.audience.clone(),
+            leeway: config.leeway.as_secs(),
+        })
+    }
+
+    pub fn verify(&self, token: &str) -> Result<Caller, AuthError> {
+        let header = decode_header(token).map_err(|_| invalid("Malformed token"))?;
+        let key = match header.alg {
+            Algorithm::HS256 => self.secret.as_ref(),
+            Algorithm::RS256 => self.rsa_key(header.kid.as_deref()),
+            _ => return Err(invalid("Unsupported signing algorithm")),
+        }
+        .ok_or_else(|| invalid("No key available for this token"))?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.leeway = self.leeway;
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.audience]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+        let data = decode::<Claims>(token, key, &validation).map_err(|error| {
+            invalid(match error.kind() {
+                ErrorKind::ExpiredSignature => "Token has expired",
+                ErrorKind::ImmatureSignature => "Token is not valid yet",
+                ErrorKind::InvalidIssuer => "Token issuer is not accepted",
+                ErrorKind::InvalidAudience => "Token audience is not accepted",
+                ErrorKind::InvalidSignature => "Token signature is invalid",
+                ErrorKind::MissingRequiredClaim(_) => "Token is missing a required claim",
+                _ => "Malformed token",
+            })
+        })?;
+        let roles = data
+            .claims
+            .roles
+            .iter()
+            .filter_map(|role| Role::parse(role))
+            .collect();
+        Ok(Caller::new(data.claims.sub, roles))
+    }
+
+    /// Picks the key named by `kid`, or the only key when the token names none.
+    fn rsa_key(&self, kid: Option<&str>) -> Option<&DecodingKey> {
+        match kid {
+            Some(kid) => self
+                .rsa_keys
+                .iter()
+                .find(|key| key.kid.as_deref() == Some(kid)),
+            None if self.rsa_keys.len() == 1 => self.rsa_keys.first(),
+            None => None,
+        }
+        .map(|key| &key.key)
+    }
+}
+
+#[derive(Serialize)]
+struct IssuedClaims<'a> {
+    sub: String,
+    iss: &'a str,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+    roles: Vec<&'static str>,
+}
+
+/// Signs the access tokens handed out at login: RS256 with the configured
+/// private key when there is one, otherwise HS256 with the shared secret.
+pub struct JwtIssuer {
+    signing: Option<(Header, EncodingKey)>,
+    issuer: String,
+    audience: String,
+    ttl: u64,
+}
+
+impl JwtIssuer {
+    pub fn from_config(config: &AuthConfig) -> Result<Self, String> {
+        let signing = match (&config.signing_key_file, &config.jwt_secret) {
+            (Some(path), _) => {
+                let pem = fs::read(path).map_err(|error| {
+                    format!("Cannot read signing key from {}: {}", path.display(), error)
+                })?;
+                let key = EncodingKey::from_rsa_pem(&pem).map_err(|error| {
+                    format!("Invalid signing key in {}: {}", path.display(), error)
+                })?;
+                let mut header = Header::new(Algorithm::RS256);
+                header.kid = config.signing_key_id.clone();
+                Some((header, key))
+            }
+            (None, Some(secret)) => Some((
+                Header::new(Algorithm::HS256),
+                EncodingKey::from_secret(secret.as_bytes()),
+            )),
+            (None, None) => None,
+        };
+
+        Ok(JwtIssuer {
+            signing,
+            issuer: config.issuer.clone(),
+            audience: config.audience.clone(),
+            ttl: config.token_ttl.as_secs(),
+        })
+    }
+
+    /// Signs a probe token and checks that `verifier` accepts it, so a signing
+    /// key missing from the JWKS fails startup rather than every later request.
+    pub fn check_verifiable_by(&self, verifier: &JwtVerifier) -> Result<(), String> {
+        if self.signing.is_none() {
+            return Ok(());
+        }
+        let probe = User::new(0, String::new(), String::new());
+        let token = self.issue(&probe).map_err(|error| error.to_string())?;
+        verifier.verify(&token.token).map(|_| ()).map_err(|error| {
+            format!(
+                "Tokens this service issues would be rejected ({}); publish the \
+                 auth.signing_key_file key in auth.jwks_file under auth.signing_key_id",
+                error
+            )
+        })
+    }
+}
+
+impl TokenIssuer for JwtIssuer {
+    fn issue(&self, user: &User) -> Result<AccessToken, UserError> {
+        let (header, key) = self.signing.as_ref().ok_or_else(|| {
+            UserError::Unavailable("No token signing key is configured".to_string())
+        })?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|error| UserError::Storage(error.to_string()))?
+            .as_secs();
+        let claims = IssuedClaims {
+            sub: user.id.to_string(),
+            iss: &self.issuer,
+            aud: &self.audience,
+            iat: now,
+            exp: now + self.ttl,
+            roles: user.roles.iter().map(|role| role.as_str()).collect(),
+        };
+        let token = encode(header, &claims, key)
+            .map_err(|error| UserError::Storage(format!("Failed to sign token: {}", error)))?;
+        Ok(AccessToken {
+            token,
+            expires_in: self.ttl,
+        })
+    }
+}
+
+fn invalid(reason: &str) -> AuthError {
+    AuthError::InvalidToken(reason.to_string())
+}
+
+/// Keeps the RSA keys usable for RS256; other key types are ignored.
+fn load_rsa_keys(contents: &str) -> Result<Vec<RsaKey>, String> {
+    let jwks: JwkSet = serde_json::from_str(contents).map_err(|error| error.to_string())?;
+    let mut keys = Vec::new();
+    for jwk in &jwks.keys {
+        let is_rs256 = matches!(jwk.algorithm, AlgorithmParameters::RSA(_))
+            && matches!(jwk.common.key_algorithm, None | Some(KeyAlgorithm::RS256));
+        if !is_rs256 {
+            continue;
+        }
+        keys.push(RsaKey {
+            kid: jwk.common.key_id.clone(),
+            key: DecodingKey::from_jwk(jwk).map_err(|error| error.to_string())?,
+        });
+    }
+    if keys.is_empty() {
+        return Err("No RS256 keys found".to_string());
+    }
+    Ok(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    const SECRET: &str = "an-hs256-test-secret-of-32-bytes!";
+    const RSA_PRIVATE_KEY: &str = include_str!("../../tests/fixtures/jwt/rsa-private.pem");
+
+    #[derive(Serialize)]
+    struct TestClaims<'a> {
+        sub: &'a str,
+        iss: &'a str,
+        aud: &'a str,
+        exp: u64,
+        roles: Vec<&'a str>,
+    }
+
+    fn claims(iss: &'static str, aud: &'static str, expires_in: i64) -> TestClaims<'static> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+        TestClaims {
+            sub: "42",
+            iss,
+            aud,
+            exp: (now + expires_in) as u64,
+            roles: vec!["admin", "auditor"],
+        }
+    }
+
+    fn verifier(jwks: bool) -> JwtVerifier {
+        JwtVerifier::from_config(&AuthConfig {
+            jwt_secret: Some(SECRET.to_string()),
+            jwks_file: jwks.then(|| {
+                PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/jwt/jwks.json")
+            }),
+            issuer: "user-api".to_string(),
+            audience: "user-api".to_string(),
+            leeway: Duration::ZERO,
+            token_ttl: Duration::from_secs(60),
+            refresh_token_ttl: Duration::from_secs(60),
+            signing_key_file: None,
+            signing_key_id: None,
+            initial_admin_email: None,
+        })
+        .unwrap()
+    }
+
+    fn hs256(claims: &TestClaims, secret: &str) -> String {
+        encode(
+            &Header::new(Algorithm::HS256),
+            claims,
+            &EncodingKey::from_secret(secret.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    fn rs256(claims: &TestClaims, kid: Option<&str>) -> String {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = kid.map(str::to_string);
+        let key = EncodingKey::from_rsa_pem(RSA_PRIVATE_KEY.as_bytes()).unwrap();
+        encode(&header, claims, &key).unwrap()
+    }
+
+    #[test]
+    fn test_accepts_hs256_tokens() {
+        let token = hs256(&claims("user-api", "user-api", 60), SECRET);
+        assert_eq!(
+            verifier(false).verify(&token),
+            Ok(Caller::new("42".to_string(), vec![Role::Admin]))
+        );
+    }
+
+    #[test]
+    fn test_accepts_rs256_tokens_from_the_jwks() {
+        let verifier = verifier(true);
+        let claims = claims("user-api", "user-api", 60);
+        assert!(verifier.verify(&rs256(&claims, Some("test-key"))).is_ok());
+        assert!(verifier.verify(&rs256(&claims, None)).is_ok());
+        assert!(verifier.verify(&rs256(&claims, Some("other-key"))).is_err());
+    }
+
+    #[test]
+    fn test_rs256_tokens_need_a_jwks() {
+        let token = rs256(&claims("user-api", "user-api", 60), None);
+        assert_eq!(
+            verifier(false).verify(&token),
+            Err(invalid("No key available for this token"))
+        );
+    }
+
+    #[test]
+    fn test_issued_tokens_verify() {
+        let config = AuthConfig {
+            jwt_secret: Some(SECRET.to_string()),
+            jwks_file: Some(
+                PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/jwt/jwks.json"),
+            ),
+            issuer: "user-api".to_string(),
+            audience: "user-api".to_string(),
+            leeway: Duration::ZERO,
+            token_ttl: Duration::from_secs(60),
+            refresh_token_ttl: Duration::from_secs(60),
+            signing_key_file: None,
+            signing_key_id: None,
+            initial_admin_email: None,
+        };
+        let user = User::new(42, "John".to_string(), "john@email.com".to_string())
+            .with_roles(vec![Role::User]);
+        let expected = Ok(Caller::new("42".to_string(), vec![Role::User]));
+
+        let issued = JwtIssuer::from_config(&config)
+            .unwrap()
+            .issue(&user)
+            .unwrap();
+        assert_eq!(issued.expires_in, 60);
+        assert_eq!(verifier(true).verify(&issued.token), expected);
+
+        let rsa_config = AuthConfig {
+            signing_key_file: Some(
+                PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                    .join("tests/fixtures/jwt/rsa-private.pem"),
+            ),
+            signing_key_id: Some("test-key".to_string()),
+            ..config
+        };
+        let issued = JwtIssuer::from_config(&rsa_config)
+            .unwrap()
+            .issue(&user)
+            .unwrap();
+        assert_eq!(decode_header(&issued.token).unwrap().alg, Algorithm::RS256);
+        assert_eq!(verifier(true).verify(&issued.token), expected);
+    }
+
+    #[test]
+    fn test_signing_key_must_be_in_the_jwks() {
+        let fixture = |name: &str| {
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("tests/fixtures/jwt/{}", name))
+        };
+        let config = |jwks_file: Option<PathBuf>, signing_key_id: &str| AuthConfig {
+            jwt_secret: None,
+            jwks_file,
+            issuer: "user-api".to_string(),
+            audience: "user-api".to_string(),
+            leeway: Duration::ZERO,
+            token_ttl: Duration::from_secs(60),
+            refresh_token_ttl: Duration::from_secs(60),
+            signing_key_file: Some(fixture("rsa-private.pem")),
+            signing_key_id: Some(signing_key_id.to_string()),
+            initial_admin_email: None,
+        };
+        let check = |config: AuthConfig| {
+            JwtIssuer::from_config(&config)
+                .unwrap()
+                .check_verifiable_by(&JwtVerifier::from_config(&config).unwrap())
+        };
+
+        assert_eq!(
+            check(config(Some(fixture("jwks.json")), "test-key")),
+            Ok(())
+        );
+        assert!(check(config(Some(fixture("jwks.json")), "other-key")).is_err());
+        assert!(check(config(None, "test-key")).is_err());
+    }
+
+    #[test]
+    fn test_rejects_expired_tokens() {
+        let token = hs256(&claims("user-api", "user-api", -10), SECRET);
+        assert_eq!(
+            verifier(false).verify(&token),
+            Err(invalid("Token has expired"))
+        );
+    }
+
+    #[test]
+    fn test_rejects_foreign_issuer_and_audience() {
+        let verifier = verifier(false);
+        let token = hs256(&claims("someone-else", "user-api", 60), SECRET);
+        assert_eq!(
+            verifier.verify(&token),
+            Err(invalid("Token issuer is not accepted"))
+        );
+        let token = hs256(&claims("user-api", "another-api", 60), SECRET);
+        assert_eq!(
+            verifier.verify(&token),
+            Err(invalid("Token audience is not accepted"))
+        );
+    }
+
+    #[test]
+    fn test_rejects_bad_signatures_and_garbage() {
+        let verifier = verifier(false);
+        let token = hs256(
+            &claims("user-api", "user-api", 60),
+            "a-different-secret-of-32-bytes!!",
+        );
+        assert_eq!(
+            verifier.verify(&token),
+            Err(invalid("Token signature is invalid"))
+        );
+        assert_eq!(
+            verifier.verify("not-a-jwt"),
+            Err(invalid("Malformed token"))
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use crate::config::log_config::{LogConfig, LogFormat};
+use tracing_subscriber::EnvFilter;
+
+/// Installs the global `tracing` subscriber. JSON output puts each event on one
+/// line with the fields of its enclosing spans, such as `request_id`.
+pub fn init_logging(config: &LogConfig) {
+    let filter = EnvFilter::new(&config.level);
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+    match config.format {
+        LogFormat::Pretty => builder.init(),
+        LogFormat::Json => builder
+            .json()
+            .flatten_event(true)
+            .with_current_span(false)
+            .with_span_list(true)
+            .init(),
+    }
+}
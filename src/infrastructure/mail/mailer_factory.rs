@@ -0,0 +1,16 @@
+use crate::config::mail_config::{MailConfig, MailTransport};
+use crate::core::services::mailer::Mailer;
+use crate::infrastructure::mail::outbox_mailer::OutboxMailer;
+use crate::infrastructure::mail::smtp_mailer::SmtpMailer;
+use std::sync::Arc;
+
+/// Builds the configured transport once; it is shared by every worker.
+pub fn mailer_factory(config: &MailConfig) -> Result<Arc<dyn Mailer>, String> {
+    match (config.transport, &config.outbox_file, &config.smtp) {
+        (MailTransport::Smtp, _, Some(smtp)) => Ok(Arc::new(SmtpMailer::new(smtp, &config.from)?)),
+        (MailTransport::File, Some(path), _) => {
+            Ok(Arc::new(OutboxMailer::to_file(path.clone(), &config.from)))
+        }
+        _ => Ok(Arc::new(OutboxMailer::in_memory(&config.from))),
+    }
+}
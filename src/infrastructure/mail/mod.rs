@@ -0,0 +1,3 @@
+pub mod mailer_factory;
+pub mod outbox_mailer;
+pub mod smtp_mailer;
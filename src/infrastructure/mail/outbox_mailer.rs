@@ -0,0 +1,143 @@
+use crate::core::errors::user_error::UserError;
+use crate::core::services::mailer::{MailMessage, Mailer};
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::json;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokio::io::AsyncWriteExt;
+
+enum Outbox {
+    Memory(Arc<Mutex<Vec<MailMessage>>>),
+    File(PathBuf),
+}
+
+/// Keeps messages instead of delivering them, so flows that send email can
+/// run without a mail server.
+pub struct OutboxMailer {
+    outbox: Outbox,
+    from: String,
+}
+
+impl OutboxMailer {
+    pub fn in_memory(from: &str) -> Self {
+        OutboxMailer {
+            outbox: Outbox::Memory(Arc::new(Mutex::new(Vec::new()))),
+            from: from.to_string(),
+        }
+    }
+
+    /// Appends one JSON object per message to `path`.
+    pub fn to_file(path: PathBuf, from: &str) -> Self {
+        OutboxMailer {
+            outbox: Outbox::File(path),
+            from: from.to_string(),
+        }
+    }
+
+    /// Messages sent so far; always empty for a file outbox.
+    #[cfg(test)]
+    pub fn messages(&self) -> Vec<MailMessage> {
+        match &self.outbox {
+            Outbox::Memory(messages) => messages.lock().unwrap().clone(),
+            Outbox::File(_) => Vec::new(),
+        }
+    }
+
+    /// The `token=` value of the link in the latest message, if any.
+    #[cfg(test)]
+    pub fn last_token(&self) -> Option<String> {
+        let body = self.messages().pop()?.body;
+        let (_, link) = body.split_once("token=")?;
+        link.split_whitespace().next().map(str::to_string)
+    }
+}
+
+#[async_trait]
+impl Mailer for OutboxMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), UserError> {
+        match &self.outbox {
+            Outbox::Memory(messages) => {
+                messages
+                    .lock()
+                    .map_err(|_| UserError::Storage("Failed to lock the outbox".to_string()))?
+                    .push(message.clone());
+            }
+            Outbox::File(path) => {
+                let mut line = json!({
+                    "from": self.from,
+                    "to": message.to,
+                    "subject": message.subject,
+                    "body": message.body,
+                    "sent_at": Utc::now(),
+                })
+                .to_string();
+                line.push('\n');
+                let write_error = |error: std::io::Error| {
+                    UserError::Unavailable(format!("Failed to write {}: {}", path.display(), error))
+                };
+                let mut file = tokio::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .await
+                    .map_err(write_error)?;
+                file.write_all(line.as_bytes()).await.map_err(write_error)?;
+                // tokio writes in the background; without this the line may be lost on drop.
+                file.flush().await.map_err(write_error)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(to: &str) -> MailMessage {
+        MailMessage {
+            to: to.to_string(),
+            subject: "Hello".to_string(),
+            body: "Hi there".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_memory_outbox_keeps_messages() {
+        let mailer = OutboxMailer::in_memory("no-reply@localhost");
+        mailer.send(&message("john@email.com")).await.unwrap();
+        assert_eq!(mailer.messages(), vec![message("john@email.com")]);
+    }
+
+    #[tokio::test]
+    async fn test_last_token_reads_the_latest_link() {
+        let mailer = OutboxMailer::in_memory("no-reply@localhost");
+        assert_eq!(mailer.last_token(), None);
+        mailer.send(&message("john@email.com")).await.unwrap();
+        assert_eq!(mailer.last_token(), None);
+        let mut link = message("john@email.com");
+        link.body = "Open http://localhost/verify?token=abc123\n\nThanks".to_string();
+        mailer.send(&link).await.unwrap();
+        assert_eq!(mailer.last_token(), Some("abc123".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_file_outbox_appends_json_lines() {
+        let path = std::env::temp_dir().join(format!("outbox-{}.jsonl", uuid::Uuid::new_v4()));
+        let mailer = OutboxMailer::to_file(path.clone(), "no-reply@localhost");
+        mailer.send(&message("john@email.com")).await.unwrap();
+        mailer.send(&message("jane@email.com")).await.unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let recipients = contents
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap()["to"].clone())
+            .collect::<Vec<serde_json::Value>>();
+        assert_eq!(
+            recipients,
+            vec![json!("john@email.com"), json!("jane@email.com")]
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use crate::config::mail_config::{SmtpConfig, SmtpTls};
+use crate::core::errors::user_error::UserError;
+use crate::core::services::mailer::{MailMessage, Mailer};
+use async_trait::async_trait;
+use lettre::message::header::ContentType;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(config: &SmtpConfig, from: &str) -> Result<Self, String> {
+        let from = from
+            .parse::<Mailbox>()
+            .map_err(|error| format!("Invalid mail.from {:?}: {}", from, error))?;
+        let relay_error = |error| format!("Invalid SMTP relay {}: {}", config.host, error);
+        let builder = match config.tls {
+            SmtpTls::Tls => {
+                AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host).map_err(relay_error)?
+            }
+            SmtpTls::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
+                .map_err(relay_error)?,
+            SmtpTls::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host),
+        };
+        let mut builder = builder.port(config.port);
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+        Ok(SmtpMailer {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: &MailMessage) -> Result<(), UserError> {
+        let to = message.to.parse::<Mailbox>().map_err(|error| {
+            UserError::Storage(format!("Invalid recipient {:?}: {}", message.to, error))
+        })?;
+        let email = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(&message.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(message.body.clone())
+            .map_err(|error| UserError::Storage(format!("Failed to build email: {}", error)))?;
+        self.transport
+            .send(email)
+            .await
+            .map_err(|error| UserError::Unavailable(format!("Failed to send email: {}", error)))?;
+        Ok(())
+    }
+}
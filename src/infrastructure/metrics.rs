@@ -0,0 +1,153 @@
+use crate::infrastructure::database::postgres::database_manager::ConnectionPool;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts,
+    Registry, TextEncoder,
+};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+/// Process-wide metrics, registered once and shared by every worker.
+pub static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+pub struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    repository_duration: HistogramVec,
+    pool_connections: IntGaugeVec,
+    pool_max_size: IntGauge,
+    pool_wait_duration: Histogram,
+}
+
+impl Metrics {
+    fn new() -> Self {
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests handled"),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Time from routing a request until its response is ready",
+            ),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let repository_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "repository_operation_duration_seconds",
+                "Time spent in a PostgresUserRepository operation, including pool checkout",
+            ),
+            &["operation", "outcome"],
+        )
+        .unwrap();
+        let pool_connections = IntGaugeVec::new(
+            Opts::new("db_pool_connections", "Database pool connections by state"),
+            &["state"],
+        )
+        .unwrap();
+        let pool_max_size = IntGauge::new(
+            "db_pool_max_connections",
+            "Maximum number of connections in the database pool",
+        )
+        .unwrap();
+        let pool_wait_duration = Histogram::with_opts(
+            HistogramOpts::new(
+                "db_pool_wait_duration_seconds",
+                "Time spent waiting to check a connection out of the pool",
+            )
+            .buckets(vec![
+                0.0005, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 30.0,
+            ]),
+        )
+        .unwrap();
+
+        let registry = Registry::new();
+        registry.register(Box::new(http_requests.clone())).unwrap();
+        registry
+            .register(Box::new(http_request_duration.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(repository_duration.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(pool_connections.clone()))
+            .unwrap();
+        registry.register(Box::new(pool_max_size.clone())).unwrap();
+        registry
+            .register(Box::new(pool_wait_duration.clone()))
+            .unwrap();
+
+        Metrics {
+            registry,
+            http_requests,
+            http_request_duration,
+            repository_duration,
+            pool_connections,
+            pool_max_size,
+            pool_wait_duration,
+        }
+    }
+
+    /// `route` is the matched pattern, e.g. `/user/{id}`, so ids do not
+    /// create a series each.
+    pub fn observe_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+        self.http_requests.with_label_values(&labels).inc();
+        self.http_request_duration
+            .with_label_values(&labels)
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn observe_repository(&self, operation: &str, outcome: &str, elapsed: Duration) {
+        self.repository_duration
+            .with_label_values(&[operation, outcome])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn observe_pool_wait(&self, elapsed: Duration) {
+        self.pool_wait_duration.observe(elapsed.as_secs_f64());
+    }
+
+    /// Samples the pool gauges and encodes everything in the Prometheus text format.
+    pub fn render(&self, pool: Option<&ConnectionPool>) -> String {
+        if let Some(pool) = pool {
+            let state = pool.state();
+            let idle = i64::from(state.idle_connections);
+            let in_use = i64::from(state.connections) - idle;
+            self.pool_connections.with_label_values(&["idle"]).set(idle);
+            self.pool_connections
+                .with_label_values(&["in_use"])
+                .set(in_use);
+            self.pool_max_size.set(i64::from(pool.max_size()));
+        }
+
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_includes_observed_series() {
+        METRICS.observe_request("GET", "/user/{id}", 404, Duration::from_millis(3));
+        METRICS.observe_repository("get_user_by_id", "not_found", Duration::from_millis(2));
+
+        let output = METRICS.render(None);
+
+        assert!(
+            output.contains(r#"http_requests_total{method="GET",route="/user/{id}",status="404"}"#)
+        );
+        assert!(output.contains(
+            r#"repository_operation_duration_seconds_count{operation="get_user_by_id",outcome="not_found"}"#
+        ));
+    }
+}
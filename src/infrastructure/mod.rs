@@ -1 +1,8 @@
+pub mod credentials;
 pub mod database;
+pub mod jwt;
+pub mod logging;
+pub mod mail;
+pub mod metrics;
+pub mod shutdown;
+pub mod tls;
@@ -0,0 +1,87 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::signal::unix::{signal, SignalKind};
+
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Shutdown state shared by every worker: a flag that makes readiness fail and
+/// a count of requests still being handled.
+#[derive(Clone, Default)]
+pub struct Shutdown {
+    started: Arc<AtomicBool>,
+    in_flight: Arc<AtomicUsize>,
+}
+
+/// Counts a request, or work it left running, as in flight until dropped.
+pub struct InFlightGuard {
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl Shutdown {
+    pub fn begin(&self) {
+        self.started.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.started.load(Ordering::SeqCst)
+    }
+
+    pub fn track(&self) -> InFlightGuard {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        InFlightGuard {
+            in_flight: self.in_flight.clone(),
+        }
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Waits until no request is in flight; returns `false` if `timeout` passes first.
+    pub async fn drained(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        while self.in_flight() > 0 {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+        }
+        true
+    }
+}
+
+/// Resolves with the name of the first SIGTERM or SIGINT received.
+pub async fn wait_for_signal() -> &'static str {
+    let mut terminate = signal(SignalKind::terminate()).expect("Failed to listen for SIGTERM");
+    tokio::select! {
+        _ = terminate.recv() => "SIGTERM",
+        _ = tokio::signal::ctrl_c() => "SIGINT",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_drained_waits_for_in_flight_requests() {
+        let shutdown = Shutdown::default();
+        let guard = shutdown.track();
+        assert_eq!(shutdown.in_flight(), 1);
+        assert!(!shutdown.drained(Duration::from_millis(100)).await);
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            drop(guard);
+        });
+        assert!(shutdown.drained(Duration::from_secs(5)).await);
+        assert_eq!(shutdown.in_flight(), 0);
+    }
+}
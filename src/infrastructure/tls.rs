@@ -0,0 +1,136 @@
+use crate::config::server_config::TlsConfig;
+use rustls::crypto::ring::default_provider;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use std::sync::Arc;
+
+/// Builds a rustls server configuration from PEM files: a certificate chain
+/// (leaf first) and its private key in PKCS#8, PKCS#1 or SEC1 form.
+pub fn load_rustls_config(tls: &TlsConfig) -> Result<rustls::ServerConfig, String> {
+    let cert_chain = CertificateDer::pem_file_iter(&tls.cert_path)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|error| {
+            format!(
+                "Cannot read certificates from {}: {}",
+                tls.cert_path.display(),
+                error
+            )
+        })?;
+    if cert_chain.is_empty() {
+        return Err(format!(
+            "No certificates found in {}",
+            tls.cert_path.display()
+        ));
+    }
+    let key = PrivateKeyDer::from_pem_file(&tls.key_path).map_err(|error| {
+        format!(
+            "Cannot read private key from {}: {}",
+            tls.key_path.display(),
+            error
+        )
+    })?;
+
+    rustls::ServerConfig::builder_with_provider(Arc::new(default_provider()))
+        .with_safe_default_protocol_versions()
+        .and_then(|builder| {
+            builder
+                .with_no_client_auth()
+                .with_single_cert(cert_chain, key)
+        })
+        .map_err(|error| format!("Invalid TLS configuration: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{web, App, HttpResponse, HttpServer};
+    use std::fs;
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+
+    struct TestCert {
+        cert_pem: String,
+        tls: TlsConfig,
+    }
+
+    impl Drop for TestCert {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.tls.cert_path);
+            let _ = fs::remove_file(&self.tls.key_path);
+        }
+    }
+
+    fn self_signed(name: &str) -> TestCert {
+        let certified = rcgen::generate_simple_self_signed(vec!["localhost".to_string()]).unwrap();
+        let directory = std::env::temp_dir();
+        let prefix = format!("user-api-{}-{}", std::process::id(), name);
+        let cert_path = directory.join(format!("{}-cert.pem", prefix));
+        let key_path = directory.join(format!("{}-key.pem", prefix));
+        let cert_pem = certified.cert.pem();
+        fs::write(&cert_path, &cert_pem).unwrap();
+        fs::write(&key_path, certified.key_pair.serialize_pem()).unwrap();
+        TestCert {
+            cert_pem,
+            tls: TlsConfig {
+                cert_path,
+                key_path,
+            },
+        }
+    }
+
+    #[test]
+    fn test_missing_files_are_reported() {
+        let tls = TlsConfig {
+            cert_path: PathBuf::from("/nonexistent/cert.pem"),
+            key_path: PathBuf::from("/nonexistent/key.pem"),
+        };
+        assert!(load_rustls_config(&tls)
+            .unwrap_err()
+            .starts_with("Cannot read certificates"));
+    }
+
+    #[test]
+    fn test_mismatched_key_is_rejected() {
+        let first = self_signed("first");
+        let second = self_signed("second");
+        let tls = TlsConfig {
+            cert_path: first.tls.cert_path.clone(),
+            key_path: second.tls.key_path.clone(),
+        };
+        assert!(load_rustls_config(&tls).is_err());
+    }
+
+    #[actix_web::test]
+    async fn test_serves_https_with_self_signed_cert() {
+        let cert = self_signed("serve");
+        let config = load_rustls_config(&cert.tls).unwrap();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = HttpServer::new(|| {
+            App::new().route(
+                "/",
+                web::get().to(|| async { HttpResponse::Ok().body("secure") }),
+            )
+        })
+        .workers(1)
+        .listen_rustls_0_23(listener, config)
+        .unwrap()
+        .run();
+        let handle = server.handle();
+        actix_web::rt::spawn(server);
+
+        let client = reqwest::Client::builder()
+            .add_root_certificate(reqwest::Certificate::from_pem(cert.cert_pem.as_bytes()).unwrap())
+            .build()
+            .unwrap();
+        let response = client
+            .get(format!("https://localhost:{}/", port))
+            .send()
+            .await
+            .expect("Failed to execute request.");
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.text().await.unwrap(), "secure");
+
+        handle.stop(true).await;
+    }
+}
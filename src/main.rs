@@ -5,94 +5,215 @@ mod infrastructure;
 mod presentation;
 mod schema;
 
+use crate::application::dto::bootstrap_admin_input_dto::BootstrapAdminInputDto;
+use crate::application::use_cases::bootstrap_admin::BootstrapAdminUseCase;
 use crate::config::database_config::DatabaseType::Postgres;
-use crate::config::{env::load_enviroment, settings::Settings};
+use crate::config::mail_config::MailTransport;
+use crate::config::{cli::Cli, env::load_enviroment, settings::Settings};
+use crate::core::services::user_service_impl::UserServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
+use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
 use crate::infrastructure::database::postgres::database_manager::DatabaseManager;
+use crate::infrastructure::database::postgres::docker_compose;
+use crate::infrastructure::jwt::{JwtIssuer, JwtVerifier};
+use crate::infrastructure::logging::init_logging;
+use crate::infrastructure::mail::mailer_factory::mailer_factory;
+use crate::infrastructure::shutdown::{wait_for_signal, Shutdown};
+use crate::infrastructure::tls::load_rustls_config;
+use crate::presentation::controllers::auth::configure_auth_routes;
+use crate::presentation::controllers::health::configure_health_routes;
+use crate::presentation::controllers::metrics::configure_metrics_routes;
 use crate::presentation::controllers::user::configure_user_routes;
+use crate::presentation::controllers::verification::configure_verification_routes;
+use crate::presentation::errors::request_error::{json_config, query_config};
+use crate::presentation::middleware::authenticate::authenticate;
+use crate::presentation::middleware::in_flight::track_in_flight;
+use crate::presentation::middleware::metrics::record_metrics;
+use crate::presentation::middleware::request_id::request_id;
+use actix_web::middleware::from_fn;
 use actix_web::{web, App, HttpServer};
-use std::io::Write;
-use std::process::Command;
-use std::thread;
+use clap::Parser;
+use tracing::{error, info, warn};
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     load_enviroment();
 
-    let mut settings = Settings::new();
-    let port = settings.port;
+    let cli = Cli::parse();
+    let mut settings = match Settings::load(&cli.sources()) {
+        Ok(settings) => settings,
+        Err(error) => {
+            eprint!("{}", error);
+            std::process::exit(2);
+        }
+    };
+
+    if cli.print_config {
+        println!("Settings:");
+        print!("{}", settings);
+        return Ok(());
+    }
 
-    println!("Settings:");
-    println!("environment: {}", settings.environment);
-    println!("port.......: {}", port);
-    println!("database...: {:?}", settings.database_config.database_type);
+    init_logging(&settings.log_config);
+    info!("Settings:\n{}", settings.to_string().trim_end());
 
     if settings.database_config.database_type == Postgres {
-        start_postgres();
-        check_postgres();
-        init_connection_pool(&mut settings);
+        if let Err(error) = init_connection_pool(&mut settings) {
+            error!("{}", error);
+            std::process::exit(1);
+        }
     }
 
-    println!("Starting server...");
+    if let Some(email) = settings.auth_config.initial_admin_email.clone() {
+        if let Err(error) = init_admin(&settings, email).await {
+            error!("{}", error);
+            std::process::exit(1);
+        }
+    }
+
+    info!(
+        host = %settings.server_config.host,
+        port = settings.server_config.port,
+        "Starting server..."
+    );
+
+    let server_config = settings.server_config.clone();
+    let rustls_config = match server_config.tls.as_ref().map(load_rustls_config) {
+        None => None,
+        Some(Ok(rustls_config)) => Some(rustls_config),
+        Some(Err(error)) => {
+            error!("{}", error);
+            std::process::exit(1);
+        }
+    };
+
+    if !settings.auth_config.has_keys() {
+        warn!("No token keys configured; every authenticated route will answer 401");
+    }
+    let jwt_verifier = match JwtVerifier::from_config(&settings.auth_config) {
+        Ok(jwt_verifier) => web::Data::new(jwt_verifier),
+        Err(error) => {
+            error!("{}", error);
+            std::process::exit(1);
+        }
+    };
+
+    let jwt_issuer = match JwtIssuer::from_config(&settings.auth_config) {
+        Ok(jwt_issuer) => web::Data::new(jwt_issuer),
+        Err(error) => {
+            error!("{}", error);
+            std::process::exit(1);
+        }
+    };
+    if let Err(error) = jwt_issuer.check_verifiable_by(&jwt_verifier) {
+        error!("{}", error);
+        std::process::exit(1);
+    }
 
-    HttpServer::new(move || {
+    if settings.mail_config.transport == MailTransport::Memory {
+        warn!("Mail transport is memory; emails are kept in process and never delivered");
+    }
+    let mailer = match mailer_factory(&settings.mail_config) {
+        Ok(mailer) => web::Data::from(mailer),
+        Err(error) => {
+            error!("{}", error);
+            std::process::exit(1);
+        }
+    };
+
+    let connection_pool = settings.connection_pool.clone();
+    let shutdown = Shutdown::default();
+    let app_shutdown = web::Data::new(shutdown.clone());
+    let mut server = HttpServer::new(move || {
         App::new()
             .app_data(web::Data::new(settings.clone()))
+            .app_data(app_shutdown.clone())
+            .app_data(jwt_verifier.clone())
+            .app_data(jwt_issuer.clone())
+            .app_data(mailer.clone())
+            .app_data(json_config())
+            .app_data(query_config())
+            .configure(configure_health_routes)
+            .configure(configure_auth_routes)
             .configure(configure_user_routes)
+            .configure(configure_verification_routes)
+            .configure(configure_metrics_routes)
+            .wrap(from_fn(authenticate))
+            .wrap(from_fn(record_metrics))
+            .wrap(from_fn(track_in_flight))
+            .wrap(from_fn(request_id))
     })
-    .bind(format!("127.0.0.1:{}", port))?
-    .run()
-    .await
-}
+    .keep_alive(server_config.keep_alive)
+    .backlog(server_config.backlog)
+    .max_connections(server_config.max_connections)
+    // Requests are drained before `stop`; this only bounds flushing their
+    // responses and closing idle keep-alive connections.
+    .shutdown_timeout(1)
+    .disable_signals();
+    if let Some(workers) = server_config.workers {
+        server = server.workers(workers);
+    }
 
-fn start_postgres() {
-    let status = Command::new("docker")
-        .arg("compose")
-        .arg("up")
-        .arg("-d")
-        .status()
-        .unwrap();
+    let address = (server_config.host.as_str(), server_config.port);
+    let server = match rustls_config {
+        Some(rustls_config) => server.bind_rustls_0_23(address, rustls_config)?,
+        None => server.bind(address)?,
+    };
+    let server = server.run();
 
-    if !status.success() {
-        panic!("Failed to start docker-compose");
-    }
-}
+    let handle = server.handle();
+    actix_web::rt::spawn(async move {
+        let signal = wait_for_signal().await;
+        info!(signal, "Received {}, shutting down...", signal);
+        shutdown.begin();
+        actix_web::rt::time::sleep(server_config.shutdown_delay).await;
 
-fn check_postgres() {
-    let output = Command::new("docker")
-        .arg("exec")
-        .arg("user-api-db")
-        .arg("pg_isready")
-        .arg("--host")
-        .arg("localhost")
-        .output()
-        .unwrap();
-
-    loop {
-        if String::from_utf8_lossy(&output.stdout).contains("accepting connections") {
-            break;
-        }
-        for i in 0..4 {
-            print!(
-                "\rWaiting for postgres to accept connections{}",
-                " ".repeat(4)
-            );
-            print!(
-                "\rWaiting for postgres to accept connections{}",
-                ".".repeat(i)
+        handle.pause().await;
+        info!(
+            in_flight = shutdown.in_flight(),
+            "Draining in-flight requests for up to {}s...",
+            server_config.shutdown_timeout.as_secs()
+        );
+        if !shutdown.drained(server_config.shutdown_timeout).await {
+            warn!(
+                in_flight = shutdown.in_flight(),
+                "Shutdown deadline reached with requests still in flight"
             );
-            std::io::stdout().flush().unwrap();
-            thread::sleep(std::time::Duration::from_secs(1));
         }
+        handle.stop(true).await;
+    });
+    server.await?;
+
+    if let Some(pool) = connection_pool {
+        info!(
+            connections = pool.state().connections,
+            "Closing database pool..."
+        );
+        drop(pool);
     }
+    info!("Server stopped");
+    Ok(())
 }
 
-fn init_connection_pool(settings: &mut Settings) {
-    let database_manager = DatabaseManager::new(&format!(
-        "postgres://{}:{}@{}/{}",
-        settings.database_config.user,
-        settings.database_config.password,
-        settings.database_config.host,
-        settings.database_config.database
-    ));
+fn init_connection_pool(settings: &mut Settings) -> Result<(), String> {
+    if settings.dev_docker_compose {
+        docker_compose::start_database()?;
+    }
+
+    let database_manager =
+        DatabaseManager::connect(&settings.database_config).map_err(|error| error.to_string())?;
     settings.connection_pool = Some(database_manager.get_pool());
+    Ok(())
+}
+
+/// Grants admin to `auth.initial_admin_email`, so a fresh deployment has
+/// someone who can create the other accounts.
+async fn init_admin(settings: &Settings, email: String) -> Result<(), String> {
+    let repository = user_repository_factory(settings);
+    let hasher = Box::new(Argon2PasswordHasher::new(&settings.credentials_config));
+    let service = Box::new(UserServiceImpl::new(repository, hasher));
+    BootstrapAdminUseCase::new(service)
+        .execute(BootstrapAdminInputDto { email })
+        .await
+        .map_err(|error| format!("Failed to set up auth.initial_admin_email: {}", error))
 }
@@ -0,0 +1,53 @@
+use crate::application::dto::forgot_password_input_dto::ForgotPasswordInputDto;
+use crate::application::use_cases::forgot_password::ForgotPasswordUseCase;
+use crate::config::settings::Settings;
+use crate::core::services::mailer::Mailer;
+use crate::core::services::password_reset_service_impl::PasswordResetServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
+use crate::infrastructure::database::factories::password_reset_repository_factory::password_reset_repository_factory;
+use crate::infrastructure::database::factories::session_repository_factory::session_repository_factory;
+use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
+use crate::infrastructure::shutdown::Shutdown;
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+use tracing::Instrument;
+
+#[derive(Deserialize)]
+struct Body {
+    email: String,
+}
+
+/// Always 202, whether or not an account uses the email. The lookup and the
+/// email run after the response, so its timing does not tell them apart either.
+#[actix_web::post("/auth/forgot-password")]
+async fn forgot_password(
+    data: web::Data<Settings>,
+    mailer: web::Data<dyn Mailer>,
+    shutdown: web::Data<Shutdown>,
+    dto: web::Json<Body>,
+) -> HttpResponse {
+    let service = Box::new(PasswordResetServiceImpl::new(
+        user_repository_factory(&data),
+        password_reset_repository_factory(&data),
+        session_repository_factory(&data),
+        Box::new(Argon2PasswordHasher::new(&data.credentials_config)),
+        mailer.into_inner(),
+        &data.mail_config.public_url,
+        data.mail_config.password_reset_ttl,
+    ));
+    let forgot_password_use_case = ForgotPasswordUseCase::new(service);
+    let dto = ForgotPasswordInputDto {
+        email: dto.into_inner().email,
+    };
+    // Shutdown waits for the email like it waits for a request.
+    let guard = shutdown.track();
+    actix_web::rt::spawn(
+        async move {
+            // Failures are logged by the use case; the client already has its answer.
+            let _ = forgot_password_use_case.execute(dto).await;
+            drop(guard);
+        }
+        .in_current_span(),
+    );
+    HttpResponse::Accepted().finish()
+}
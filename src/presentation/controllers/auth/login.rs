@@ -0,0 +1,64 @@
+use crate::application::dto::login_input_dto::LoginInputDto;
+use crate::application::use_cases::login::LoginUseCase;
+use crate::config::settings::Settings;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::session_service_impl::SessionServiceImpl;
+use crate::core::services::user_service_impl::UserServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
+use crate::infrastructure::database::factories::session_repository_factory::session_repository_factory;
+use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
+use crate::infrastructure::jwt::JwtIssuer;
+use actix_web::http::header::USER_AGENT;
+use actix_web::{web, HttpRequest, HttpResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+struct Body {
+    email: String,
+    password: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Response {
+    access_token: String,
+    token_type: &'static str,
+    expires_in: u64,
+    refresh_token: String,
+}
+
+#[actix_web::post("/auth/login")]
+async fn login(
+    request: HttpRequest,
+    data: web::Data<Settings>,
+    issuer: web::Data<JwtIssuer>,
+    dto: web::Json<Body>,
+) -> Result<HttpResponse, UserError> {
+    let repository = user_repository_factory(&data);
+    let hasher = Box::new(Argon2PasswordHasher::new(&data.credentials_config));
+    let service = Box::new(UserServiceImpl::new(repository, hasher));
+    let sessions = Box::new(SessionServiceImpl::new(
+        session_repository_factory(&data),
+        data.auth_config.refresh_token_ttl,
+    ));
+    let login_use_case = LoginUseCase::new(service, sessions, issuer.into_inner());
+    let body = dto.into_inner();
+    let token = login_use_case
+        .execute(LoginInputDto {
+            email: body.email,
+            password: body.password,
+            user_agent: request
+                .headers()
+                .get(USER_AGENT)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+            // The socket address; forwarding headers are not trusted here.
+            ip_address: request.peer_addr().map(|address| address.ip().to_string()),
+        })
+        .await?;
+    Ok(HttpResponse::Ok().json(Response {
+        access_token: token.access_token,
+        token_type: "Bearer",
+        expires_in: token.expires_in,
+        refresh_token: token.refresh_token,
+    }))
+}
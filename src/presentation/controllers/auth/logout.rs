@@ -0,0 +1,31 @@
+use crate::application::dto::logout_input_dto::LogoutInputDto;
+use crate::application::use_cases::logout::LogoutUseCase;
+use crate::config::settings::Settings;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::session_service_impl::SessionServiceImpl;
+use crate::infrastructure::database::factories::session_repository_factory::session_repository_factory;
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Body {
+    refresh_token: String,
+}
+
+#[actix_web::post("/auth/logout")]
+async fn logout(
+    data: web::Data<Settings>,
+    dto: web::Json<Body>,
+) -> Result<HttpResponse, UserError> {
+    let sessions = Box::new(SessionServiceImpl::new(
+        session_repository_factory(&data),
+        data.auth_config.refresh_token_ttl,
+    ));
+    let logout_use_case = LogoutUseCase::new(sessions);
+    logout_use_case
+        .execute(LogoutInputDto {
+            refresh_token: dto.into_inner().refresh_token,
+        })
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,15 @@
+use actix_web::web;
+
+mod forgot_password;
+mod login;
+mod logout;
+mod refresh;
+mod reset_password;
+
+pub fn configure_auth_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(login::login);
+    cfg.service(refresh::refresh);
+    cfg.service(logout::logout);
+    cfg.service(forgot_password::forgot_password);
+    cfg.service(reset_password::reset_password);
+}
@@ -0,0 +1,53 @@
+use crate::application::dto::refresh_session_input_dto::RefreshSessionInputDto;
+use crate::application::use_cases::refresh_session::RefreshSessionUseCase;
+use crate::config::settings::Settings;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::session_service_impl::SessionServiceImpl;
+use crate::core::services::user_service_impl::UserServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
+use crate::infrastructure::database::factories::session_repository_factory::session_repository_factory;
+use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
+use crate::infrastructure::jwt::JwtIssuer;
+use actix_web::{web, HttpResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+struct Body {
+    refresh_token: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Response {
+    access_token: String,
+    token_type: &'static str,
+    expires_in: u64,
+    refresh_token: String,
+}
+
+#[actix_web::post("/auth/refresh")]
+async fn refresh(
+    data: web::Data<Settings>,
+    issuer: web::Data<JwtIssuer>,
+    dto: web::Json<Body>,
+) -> Result<HttpResponse, UserError> {
+    let repository = user_repository_factory(&data);
+    let hasher = Box::new(Argon2PasswordHasher::new(&data.credentials_config));
+    let service = Box::new(UserServiceImpl::new(repository, hasher));
+    let sessions = Box::new(SessionServiceImpl::new(
+        session_repository_factory(&data),
+        data.auth_config.refresh_token_ttl,
+    ));
+    let refresh_session_use_case =
+        RefreshSessionUseCase::new(service, sessions, issuer.into_inner());
+    let token = refresh_session_use_case
+        .execute(RefreshSessionInputDto {
+            refresh_token: dto.into_inner().refresh_token,
+        })
+        .await?;
+    Ok(HttpResponse::Ok().json(Response {
+        access_token: token.access_token,
+        token_type: "Bearer",
+        expires_in: token.expires_in,
+        refresh_token: token.refresh_token,
+    }))
+}
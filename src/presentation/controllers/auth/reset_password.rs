@@ -0,0 +1,44 @@
+use crate::application::dto::reset_password_input_dto::ResetPasswordInputDto;
+use crate::application::use_cases::reset_password::ResetPasswordUseCase;
+use crate::config::settings::Settings;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::mailer::Mailer;
+use crate::core::services::password_reset_service_impl::PasswordResetServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
+use crate::infrastructure::database::factories::password_reset_repository_factory::password_reset_repository_factory;
+use crate::infrastructure::database::factories::session_repository_factory::session_repository_factory;
+use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Body {
+    token: String,
+    new_password: String,
+}
+
+#[actix_web::post("/auth/reset-password")]
+async fn reset_password(
+    data: web::Data<Settings>,
+    mailer: web::Data<dyn Mailer>,
+    dto: web::Json<Body>,
+) -> Result<HttpResponse, UserError> {
+    let service = Box::new(PasswordResetServiceImpl::new(
+        user_repository_factory(&data),
+        password_reset_repository_factory(&data),
+        session_repository_factory(&data),
+        Box::new(Argon2PasswordHasher::new(&data.credentials_config)),
+        mailer.into_inner(),
+        &data.mail_config.public_url,
+        data.mail_config.password_reset_ttl,
+    ));
+    let reset_password_use_case = ResetPasswordUseCase::new(service);
+    let body = dto.into_inner();
+    reset_password_use_case
+        .execute(ResetPasswordInputDto {
+            token: body.token,
+            new_password: body.new_password,
+        })
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
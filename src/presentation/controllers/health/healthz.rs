@@ -0,0 +1,13 @@
+use actix_web::HttpResponse;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+struct Response {
+    status: &'static str,
+}
+
+/// Liveness: answers as long as the process can serve requests.
+#[actix_web::get("/healthz")]
+async fn healthz() -> HttpResponse {
+    HttpResponse::Ok().json(Response { status: "ok" })
+}
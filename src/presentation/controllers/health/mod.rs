@@ -0,0 +1,9 @@
+use actix_web::web;
+
+mod healthz;
+mod readyz;
+
+pub fn configure_health_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(healthz::healthz);
+    cfg.service(readyz::readyz);
+}
@@ -0,0 +1,108 @@
+use crate::config::settings::Settings;
+use crate::infrastructure::database::postgres::database_health::{
+    check_connection, pending_migrations,
+};
+use crate::infrastructure::shutdown::Shutdown;
+use actix_web::{web, HttpResponse};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tracing::error;
+
+#[derive(Debug, Serialize)]
+struct Check {
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pending: Vec<String>,
+}
+
+impl Check {
+    fn up() -> Self {
+        Check {
+            status: "up",
+            error: None,
+            pending: Vec::new(),
+        }
+    }
+
+    /// `reason` is public, so driver and pool errors are logged, not passed here.
+    fn down(reason: &str) -> Self {
+        Check {
+            status: "down",
+            error: Some(reason.to_string()),
+            pending: Vec::new(),
+        }
+    }
+
+    fn is_up(&self) -> bool {
+        self.status == "up"
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Response {
+    status: &'static str,
+    checks: BTreeMap<&'static str, Check>,
+}
+
+/// Readiness: the server is not shutting down, the database answers `SELECT 1` and
+/// every embedded migration is applied. The in-memory backend has no external
+/// dependencies.
+#[actix_web::get("/readyz")]
+async fn readyz(data: web::Data<Settings>, shutdown: web::Data<Shutdown>) -> HttpResponse {
+    let mut checks = BTreeMap::new();
+
+    if shutdown.is_started() {
+        checks.insert("shutdown", Check::down("Server is shutting down"));
+    }
+
+    if let Some(pool) = data.connection_pool.clone() {
+        let (database, migrations) = web::block(move || {
+            let database = match check_connection(&pool) {
+                Ok(()) => Check::up(),
+                Err(error) => {
+                    error!(%error, "Database readiness check failed");
+                    Check::down("Database unavailable")
+                }
+            };
+            let migrations = match pending_migrations(&pool) {
+                Ok(pending) if pending.is_empty() => Check::up(),
+                Ok(pending) => Check {
+                    status: "pending",
+                    error: None,
+                    pending,
+                },
+                Err(error) => {
+                    error!(%error, "Migration readiness check failed");
+                    Check::down("Migration status unavailable")
+                }
+            };
+            (database, migrations)
+        })
+        .await
+        .unwrap_or_else(|error| {
+            error!(%error, "Readiness check task failed");
+            (
+                Check::down("Database unavailable"),
+                Check::down("Migration status unavailable"),
+            )
+        });
+        checks.insert("database", database);
+        checks.insert("migrations", migrations);
+    } else {
+        checks.insert("database", Check::up());
+    }
+
+    if checks.values().all(Check::is_up) {
+        HttpResponse::Ok().json(Response {
+            status: "ready",
+            checks,
+        })
+    } else {
+        HttpResponse::ServiceUnavailable().json(Response {
+            status: "not_ready",
+            checks,
+        })
+    }
+}
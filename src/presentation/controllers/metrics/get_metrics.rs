@@ -0,0 +1,12 @@
+use crate::config::settings::Settings;
+use crate::infrastructure::metrics::METRICS;
+use actix_web::{web, HttpResponse};
+
+const PROMETHEUS_TEXT: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+#[actix_web::get("/metrics")]
+async fn get_metrics(data: web::Data<Settings>) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(PROMETHEUS_TEXT)
+        .body(METRICS.render(data.connection_pool.as_ref()))
+}
@@ -0,0 +1,7 @@
+use actix_web::web;
+
+mod get_metrics;
+
+pub fn configure_metrics_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(get_metrics::get_metrics);
+}
@@ -0,0 +1,41 @@
+use crate::application::dto::change_password_input_dto::ChangePasswordInputDto;
+use crate::application::use_cases::change_password::ChangePasswordUseCase;
+use crate::config::settings::Settings;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::user_service_impl::UserServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
+use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Body {
+    current_password: String,
+    new_password: String,
+}
+
+#[actix_web::put("/user/{id}/password")]
+async fn change_password(
+    caller: Caller,
+    data: web::Data<Settings>,
+    id: web::Path<i32>,
+    dto: web::Json<Body>,
+) -> Result<HttpResponse, UserError> {
+    let repository = user_repository_factory(&data);
+    let hasher = Box::new(Argon2PasswordHasher::new(&data.credentials_config));
+    let service = Box::new(UserServiceImpl::new(repository, hasher));
+    let change_password_use_case = ChangePasswordUseCase::new(service);
+    let body = dto.into_inner();
+    change_password_use_case
+        .execute(
+            &caller,
+            ChangePasswordInputDto {
+                id: *id,
+                current_password: body.current_password,
+                new_password: body.new_password,
+            },
+        )
+        .await?;
+    Ok(HttpResponse::NoContent().finish())
+}
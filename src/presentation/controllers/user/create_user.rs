@@ -1,44 +1,42 @@
 use crate::application::dto::create_user_input_dto::CreateUserInputDto;
 use crate::application::use_cases::create_user::CreateUserUseCase;
 use crate::config::settings::Settings;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
 use crate::core::services::user_service_impl::UserServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
 use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
-use actix_web::{web, HttpResponse, Responder};
-use serde::{Deserialize, Serialize};
+use crate::presentation::controllers::user::user_response::UserResponse;
+use actix_web::{web, HttpResponse};
+use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 struct Body {
     name: String,
     email: String,
-}
-
-#[derive(Debug, Serialize)]
-struct Response {
-    id: i32,
-    name: String,
-    email: String,
-}
-
-#[derive(Debug, Serialize)]
-struct ErrorResponse {
-    message: String,
+    password: String,
 }
 
 #[actix_web::post("/user")]
-async fn create_user(data: web::Data<Settings>, dto: web::Json<Body>) -> impl Responder {
-    let repository = user_repository_factory(&data.connection_pool);
-    let service = Box::new(UserServiceImpl::new(repository));
+async fn create_user(
+    caller: Caller,
+    data: web::Data<Settings>,
+    dto: web::Json<Body>,
+) -> Result<HttpResponse, UserError> {
+    let repository = user_repository_factory(&data);
+    let hasher = Box::new(Argon2PasswordHasher::new(&data.credentials_config));
+    let service = Box::new(UserServiceImpl::new(repository, hasher));
     let create_user_use_case = CreateUserUseCase::new(service);
     let user = dto.into_inner();
-    match create_user_use_case.execute(CreateUserInputDto {
-        name: user.name,
-        email: user.email,
-    }) {
-        Ok(created_user) => HttpResponse::Created().json(Response {
-            id: created_user.id,
-            name: created_user.name,
-            email: created_user.email,
-        }),
-        Err(e) => HttpResponse::BadRequest().json(ErrorResponse { message: e }),
-    }
+    let created_user = create_user_use_case
+        .execute(
+            &caller,
+            CreateUserInputDto {
+                name: user.name,
+                email: user.email,
+                password: user.password,
+            },
+        )
+        .await?;
+    Ok(HttpResponse::Created().json(UserResponse::from(created_user)))
 }
@@ -0,0 +1,56 @@
+use crate::application::dto::revoke_sessions_input_dto::RevokeSessionsInputDto;
+use crate::application::use_cases::revoke_sessions::RevokeSessionsUseCase;
+use crate::config::settings::Settings;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::session_service_impl::SessionServiceImpl;
+use crate::core::services::user_service_impl::UserServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
+use crate::infrastructure::database::factories::session_repository_factory::session_repository_factory;
+use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
+use actix_web::{web, HttpResponse};
+use uuid::Uuid;
+
+async fn revoke(
+    caller: Caller,
+    data: web::Data<Settings>,
+    dto: RevokeSessionsInputDto,
+) -> Result<HttpResponse, UserError> {
+    let repository = user_repository_factory(&data);
+    let hasher = Box::new(Argon2PasswordHasher::new(&data.credentials_config));
+    let service = Box::new(UserServiceImpl::new(repository, hasher));
+    let sessions = Box::new(SessionServiceImpl::new(
+        session_repository_factory(&data),
+        data.auth_config.refresh_token_ttl,
+    ));
+    let revoke_sessions_use_case = RevokeSessionsUseCase::new(service, sessions);
+    revoke_sessions_use_case.execute(&caller, dto).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[actix_web::delete("/user/{id}/sessions")]
+async fn delete_sessions(
+    caller: Caller,
+    data: web::Data<Settings>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, UserError> {
+    let dto = RevokeSessionsInputDto {
+        user_id: *id,
+        session_id: None,
+    };
+    revoke(caller, data, dto).await
+}
+
+#[actix_web::delete("/user/{id}/sessions/{session_id}")]
+async fn delete_session(
+    caller: Caller,
+    data: web::Data<Settings>,
+    path: web::Path<(i32, Uuid)>,
+) -> Result<HttpResponse, UserError> {
+    let (user_id, session_id) = path.into_inner();
+    let dto = RevokeSessionsInputDto {
+        user_id,
+        session_id: Some(session_id),
+    };
+    revoke(caller, data, dto).await
+}
@@ -1,35 +1,25 @@
 use crate::application::dto::delete_user_input_dto::DeleteUserInputDto;
 use crate::application::use_cases::delete_user::RemoveUserUseCase;
 use crate::config::settings::Settings;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
 use crate::core::services::user_service_impl::UserServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
 use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
-use actix_web::{web, HttpResponse, Responder};
-use serde::Serialize;
-
-#[derive(Debug, Serialize)]
-struct ErrorResponse {
-    message: String,
-}
-
-#[derive(Debug, Serialize)]
-struct Response {
-    id: i32,
-    name: String,
-    email: String,
-}
+use crate::presentation::controllers::user::user_response::UserResponse;
+use actix_web::{web, HttpResponse};
 
 #[actix_web::delete("/user/{id}")]
-async fn delete_user(data: web::Data<Settings>, id: web::Path<i32>) -> impl Responder {
-    let repository = user_repository_factory(&data.connection_pool);
-    let service = Box::new(UserServiceImpl::new(repository));
+async fn delete_user(
+    caller: Caller,
+    data: web::Data<Settings>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, UserError> {
+    let repository = user_repository_factory(&data);
+    let hasher = Box::new(Argon2PasswordHasher::new(&data.credentials_config));
+    let service = Box::new(UserServiceImpl::new(repository, hasher));
     let remove_user_use_case = RemoveUserUseCase::new(service);
     let dto = DeleteUserInputDto { id: *id };
-    match remove_user_use_case.execute(dto) {
-        Ok(deleted_user) => HttpResponse::Ok().json(Response {
-            id: deleted_user.id,
-            name: deleted_user.name,
-            email: deleted_user.email,
-        }),
-        Err(e) => HttpResponse::NotFound().json(ErrorResponse { message: e }),
-    }
+    let deleted_user = remove_user_use_case.execute(&caller, dto).await?;
+    Ok(HttpResponse::Ok().json(UserResponse::from(deleted_user)))
 }
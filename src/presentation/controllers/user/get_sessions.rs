@@ -0,0 +1,65 @@
+use crate::application::dto::read_sessions_input_dto::ReadSessionsInputDto;
+use crate::application::dto::read_sessions_output_dto::ReadSessionOutputDto;
+use crate::application::use_cases::read_sessions::ReadSessionsUseCase;
+use crate::config::settings::Settings;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::session_service_impl::SessionServiceImpl;
+use crate::core::services::user_service_impl::UserServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
+use crate::infrastructure::database::factories::session_repository_factory::session_repository_factory;
+use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
+use actix_web::{web, HttpResponse};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize)]
+struct Body {
+    id: Uuid,
+    user_agent: Option<String>,
+    ip_address: Option<String>,
+    created_at: DateTime<Utc>,
+    last_used_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+}
+
+impl From<ReadSessionOutputDto> for Body {
+    fn from(dto: ReadSessionOutputDto) -> Self {
+        Body {
+            id: dto.id,
+            user_agent: dto.user_agent,
+            ip_address: dto.ip_address,
+            created_at: dto.created_at,
+            last_used_at: dto.last_used_at,
+            expires_at: dto.expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Response {
+    data: Vec<Body>,
+}
+
+#[actix_web::get("/user/{id}/sessions")]
+async fn get_sessions(
+    caller: Caller,
+    data: web::Data<Settings>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, UserError> {
+    let repository = user_repository_factory(&data);
+    let hasher = Box::new(Argon2PasswordHasher::new(&data.credentials_config));
+    let service = Box::new(UserServiceImpl::new(repository, hasher));
+    let sessions = Box::new(SessionServiceImpl::new(
+        session_repository_factory(&data),
+        data.auth_config.refresh_token_ttl,
+    ));
+    let read_sessions_use_case = ReadSessionsUseCase::new(service, sessions);
+    let output = read_sessions_use_case
+        .execute(&caller, ReadSessionsInputDto { user_id: *id })
+        .await?;
+    Ok(HttpResponse::Ok().json(Response {
+        data: output.sessions.into_iter().map(Body::from).collect(),
+    }))
+}
@@ -1,38 +1,63 @@
-use crate::application::dto::read_user_output_dto::ReadUserOutputDto;
+use crate::application::dto::read_users_input_dto::ReadUsersInputDto;
 use crate::application::use_cases::read_all_users::GetAllUsersUseCase;
 use crate::config::settings::Settings;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
 use crate::core::services::user_service_impl::UserServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
 use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
-use actix_web::{web, HttpResponse, Responder};
-use serde::Serialize;
+use crate::presentation::controllers::user::user_response::UserResponse;
+use actix_web::{web, HttpResponse};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
-struct Body {
-    id: i32,
-    name: String,
-    email: String,
+#[derive(Debug, Deserialize)]
+struct Params {
+    limit: Option<i64>,
+    cursor: Option<i32>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+    sort: Option<String>,
+    direction: Option<String>,
+    email_domain: Option<String>,
+    name_contains: Option<String>,
 }
 
-impl From<ReadUserOutputDto> for Body {
-    fn from(dto: ReadUserOutputDto) -> Self {
-        Body {
-            id: dto.id,
-            name: dto.name,
-            email: dto.email,
-        }
-    }
+#[derive(Debug, Serialize)]
+struct Response {
+    data: Vec<UserResponse>,
+    total: i64,
+    next_cursor: Option<i32>,
 }
 
 #[actix_web::get("/users")]
-async fn get_users(data: web::Data<Settings>) -> impl Responder {
-    let repository = user_repository_factory(&data.connection_pool);
-    let service = Box::new(UserServiceImpl::new(repository));
+async fn get_users(
+    caller: Caller,
+    data: web::Data<Settings>,
+    params: web::Query<Params>,
+) -> Result<HttpResponse, UserError> {
+    let repository = user_repository_factory(&data);
+    let hasher = Box::new(Argon2PasswordHasher::new(&data.credentials_config));
+    let service = Box::new(UserServiceImpl::new(repository, hasher));
     let get_all_users_use_case = GetAllUsersUseCase::new(service);
-    HttpResponse::Ok().json(
-        get_all_users_use_case
-            .execute()
-            .into_iter()
-            .map(Body::from)
-            .collect::<Vec<Body>>(),
-    )
+    let params = params.into_inner();
+    let users = get_all_users_use_case
+        .execute(
+            &caller,
+            ReadUsersInputDto {
+                limit: params.limit,
+                cursor: params.cursor,
+                page: params.page,
+                per_page: params.per_page,
+                sort: params.sort,
+                direction: params.direction,
+                email_domain: params.email_domain,
+                name_contains: params.name_contains,
+            },
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(Response {
+        data: users.users.into_iter().map(UserResponse::from).collect(),
+        total: users.total,
+        next_cursor: users.next_cursor,
+    }))
 }
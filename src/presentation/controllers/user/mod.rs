@@ -1,15 +1,29 @@
 use actix_web::web;
 
+mod change_password;
 mod create_user;
+mod delete_sessions;
 mod delete_user;
+mod get_sessions;
 mod get_user;
 mod get_users;
+mod patch_user;
+mod request_email_verification;
+mod search_users;
 mod update_user;
+mod user_response;
 
 pub fn configure_user_routes(cfg: &mut web::ServiceConfig) {
     cfg.service(get_user::get_user);
     cfg.service(create_user::create_user);
     cfg.service(delete_user::delete_user);
+    cfg.service(search_users::search_users);
     cfg.service(get_users::get_users);
     cfg.service(update_user::update_user);
+    cfg.service(patch_user::patch_user);
+    cfg.service(change_password::change_password);
+    cfg.service(get_sessions::get_sessions);
+    cfg.service(delete_sessions::delete_sessions);
+    cfg.service(delete_sessions::delete_session);
+    cfg.service(request_email_verification::request_email_verification);
 }
@@ -0,0 +1,74 @@
+use crate::application::dto::patch_user_input_dto::PatchUserInputDto;
+use crate::application::use_cases::patch_user::PatchUserUseCase;
+use crate::config::settings::Settings;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::{FieldError, UserError};
+use crate::core::services::user_service_impl::UserServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
+use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
+use crate::presentation::controllers::user::user_response::UserResponse;
+use actix_web::{web, HttpResponse};
+use serde::{Deserialize, Deserializer};
+
+/// JSON Merge Patch (RFC 7396) body: an absent member leaves the field untouched,
+/// while an explicit `null` asks for removal, which no user field allows.
+#[derive(Debug, Deserialize)]
+struct Body {
+    #[serde(default, deserialize_with = "present")]
+    name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "present")]
+    email: Option<Option<String>>,
+}
+
+fn present<'de, D>(deserializer: D) -> Result<Option<Option<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer).map(Some)
+}
+
+fn required(field: &str, value: Option<Option<String>>) -> Result<Option<String>, FieldError> {
+    match value {
+        Some(None) => Err(FieldError::new(field, "Field cannot be removed")),
+        Some(value) => Ok(value),
+        None => Ok(None),
+    }
+}
+
+#[actix_web::patch("/user/{id}")]
+async fn patch_user(
+    caller: Caller,
+    data: web::Data<Settings>,
+    id: web::Path<i32>,
+    dto: web::Json<Body>,
+) -> Result<HttpResponse, UserError> {
+    let user = dto.into_inner();
+    let mut errors = Vec::new();
+    let name = required("name", user.name).unwrap_or_else(|error| {
+        errors.push(error);
+        None
+    });
+    let email = required("email", user.email).unwrap_or_else(|error| {
+        errors.push(error);
+        None
+    });
+    if !errors.is_empty() {
+        return Err(UserError::Validation(errors));
+    }
+
+    let repository = user_repository_factory(&data);
+    let hasher = Box::new(Argon2PasswordHasher::new(&data.credentials_config));
+    let service = Box::new(UserServiceImpl::new(repository, hasher));
+    let patch_user_use_case = PatchUserUseCase::new(service);
+    let patched_user = patch_user_use_case
+        .execute(
+            &caller,
+            PatchUserInputDto {
+                id: *id,
+                name,
+                email,
+            },
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(UserResponse::from(patched_user)))
+}
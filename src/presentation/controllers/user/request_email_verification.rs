@@ -0,0 +1,31 @@
+use crate::application::dto::request_email_verification_input_dto::RequestEmailVerificationInputDto;
+use crate::application::use_cases::request_email_verification::RequestEmailVerificationUseCase;
+use crate::config::settings::Settings;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::email_verification_service_impl::EmailVerificationServiceImpl;
+use crate::core::services::mailer::Mailer;
+use crate::infrastructure::database::factories::email_verification_repository_factory::email_verification_repository_factory;
+use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
+use actix_web::{web, HttpResponse};
+
+#[actix_web::post("/user/{id}/verify-email")]
+async fn request_email_verification(
+    caller: Caller,
+    data: web::Data<Settings>,
+    mailer: web::Data<dyn Mailer>,
+    id: web::Path<i32>,
+) -> Result<HttpResponse, UserError> {
+    let service = Box::new(EmailVerificationServiceImpl::new(
+        user_repository_factory(&data),
+        email_verification_repository_factory(&data),
+        mailer.into_inner(),
+        &data.mail_config.public_url,
+        data.mail_config.verification_ttl,
+    ));
+    let request_email_verification_use_case = RequestEmailVerificationUseCase::new(service);
+    request_email_verification_use_case
+        .execute(&caller, RequestEmailVerificationInputDto { user_id: *id })
+        .await?;
+    Ok(HttpResponse::Accepted().finish())
+}
@@ -0,0 +1,70 @@
+use crate::application::dto::search_users_input_dto::SearchUsersInputDto;
+use crate::application::dto::search_users_output_dto::SearchUsersOutputDto;
+use crate::application::use_cases::search_users::SearchUsersUseCase;
+use crate::config::settings::Settings;
+use crate::core::domain::caller::Caller;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::user_service_impl::UserServiceImpl;
+use crate::infrastructure::credentials::argon2_password_hasher::Argon2PasswordHasher;
+use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
+use crate::presentation::controllers::user::user_response::UserResponse;
+use actix_web::{web, HttpResponse};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+struct Params {
+    #[serde(default)]
+    q: String,
+    limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+struct Body {
+    #[serde(flatten)]
+    user: UserResponse,
+    score: f32,
+}
+
+impl From<SearchUsersOutputDto> for Body {
+    fn from(dto: SearchUsersOutputDto) -> Self {
+        Body {
+            user: UserResponse {
+                id: dto.id,
+                name: dto.name,
+                email: dto.email,
+                email_verified_at: dto.email_verified_at,
+            },
+            score: dto.score,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Response {
+    data: Vec<Body>,
+}
+
+#[actix_web::get("/users/search")]
+async fn search_users(
+    caller: Caller,
+    data: web::Data<Settings>,
+    params: web::Query<Params>,
+) -> Result<HttpResponse, UserError> {
+    let repository = user_repository_factory(&data);
+    let hasher = Box::new(Argon2PasswordHasher::new(&data.credentials_config));
+    let service = Box::new(UserServiceImpl::new(repository, hasher));
+    let search_users_use_case = SearchUsersUseCase::new(service);
+    let params = params.into_inner();
+    let users = search_users_use_case
+        .execute(
+            &caller,
+            SearchUsersInputDto {
+                query: params.q,
+                limit: params.limit,
+            },
+        )
+        .await?;
+    Ok(HttpResponse::Ok().json(Response {
+        data: users.into_iter().map(Body::from).collect(),
+    }))
+}
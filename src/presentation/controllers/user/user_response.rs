@@ -0,0 +1,59 @@
+use crate::application::dto::create_user_output_dto::CreateUserOutputDto;
+use crate::application::dto::delete_user_output_dto::DeleteUserOutputDto;
+use crate::application::dto::read_user_output_dto::ReadUserOutputDto;
+use crate::application::dto::update_user_output_dto::UpdateUserOutputDto;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// The representation of a user shared by every route that returns one.
+#[derive(Debug, Serialize)]
+pub struct UserResponse {
+    pub(super) id: i32,
+    pub(super) name: String,
+    pub(super) email: String,
+    pub(super) email_verified_at: Option<DateTime<Utc>>,
+}
+
+impl From<ReadUserOutputDto> for UserResponse {
+    fn from(dto: ReadUserOutputDto) -> Self {
+        UserResponse {
+            id: dto.id,
+            name: dto.name,
+            email: dto.email,
+            email_verified_at: dto.email_verified_at,
+        }
+    }
+}
+
+impl From<CreateUserOutputDto> for UserResponse {
+    fn from(dto: CreateUserOutputDto) -> Self {
+        UserResponse {
+            id: dto.id,
+            name: dto.name,
+            email: dto.email,
+            email_verified_at: dto.email_verified_at,
+        }
+    }
+}
+
+impl From<UpdateUserOutputDto> for UserResponse {
+    fn from(dto: UpdateUserOutputDto) -> Self {
+        UserResponse {
+            id: dto.id,
+            name: dto.name,
+            email: dto.email,
+            email_verified_at: dto.email_verified_at,
+        }
+    }
+}
+
+impl From<DeleteUserOutputDto> for UserResponse {
+    fn from(dto: DeleteUserOutputDto) -> Self {
+        UserResponse {
+            id: dto.id,
+            name: dto.name,
+            email: dto.email,
+            email_verified_at: dto.email_verified_at,
+        }
+    }
+}
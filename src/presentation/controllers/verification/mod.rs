@@ -0,0 +1,7 @@
+use actix_web::web;
+
+mod verify_email;
+
+pub fn configure_verification_routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(verify_email::verify_email);
+}
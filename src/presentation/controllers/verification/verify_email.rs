@@ -0,0 +1,49 @@
+use crate::application::dto::verify_email_input_dto::VerifyEmailInputDto;
+use crate::application::use_cases::verify_email::VerifyEmailUseCase;
+use crate::config::settings::Settings;
+use crate::core::errors::user_error::UserError;
+use crate::core::services::email_verification_service_impl::EmailVerificationServiceImpl;
+use crate::core::services::mailer::Mailer;
+use crate::infrastructure::database::factories::email_verification_repository_factory::email_verification_repository_factory;
+use crate::infrastructure::database::factories::user_repository_factory::user_repository_factory;
+use actix_web::{web, HttpResponse};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+struct Params {
+    token: String,
+}
+
+#[derive(Debug, Serialize)]
+struct Response {
+    id: i32,
+    email: String,
+    email_verified_at: Option<DateTime<Utc>>,
+}
+
+#[actix_web::get("/verify")]
+async fn verify_email(
+    data: web::Data<Settings>,
+    mailer: web::Data<dyn Mailer>,
+    params: web::Query<Params>,
+) -> Result<HttpResponse, UserError> {
+    let service = Box::new(EmailVerificationServiceImpl::new(
+        user_repository_factory(&data),
+        email_verification_repository_factory(&data),
+        mailer.into_inner(),
+        &data.mail_config.public_url,
+        data.mail_config.verification_ttl,
+    ));
+    let verify_email_use_case = VerifyEmailUseCase::new(service);
+    let user = verify_email_use_case
+        .execute(VerifyEmailInputDto {
+            token: params.into_inner().token,
+        })
+        .await?;
+    Ok(HttpResponse::Ok().json(Response {
+        id: user.id,
+        email: user.email,
+        email_verified_at: user.email_verified_at,
+    }))
+}
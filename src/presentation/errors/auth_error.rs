@@ -0,0 +1,53 @@
+use crate::core::errors::auth_error::AuthError;
+use crate::presentation::errors::problem_details::ProblemDetails;
+use actix_web::http::header::{HeaderValue, WWW_AUTHENTICATE};
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
+
+const REALM: &str = "user-api";
+
+impl AuthError {
+    /// The RFC 6750 challenge; a missing token gets no error code.
+    fn challenge(&self) -> String {
+        match self {
+            AuthError::MissingToken => format!("Bearer realm=\"{}\"", REALM),
+            AuthError::InvalidToken(reason) => format!(
+                "Bearer realm=\"{}\", error=\"invalid_token\", error_description=\"{}\"",
+                REALM,
+                reason.replace(['"', '\\'], "")
+            ),
+        }
+    }
+}
+
+impl ResponseError for AuthError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::UNAUTHORIZED
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response =
+            ProblemDetails::new(self.status_code(), "/problems/unauthorized", "Unauthorized")
+                .with_detail(&self.to_string())
+                .to_response();
+        if let Ok(challenge) = HeaderValue::from_str(&self.challenge()) {
+            response.headers_mut().insert(WWW_AUTHENTICATE, challenge);
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_token_challenge_carries_the_reason() {
+        let response = AuthError::InvalidToken("Token has expired".to_string()).error_response();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            response.headers().get(WWW_AUTHENTICATE).unwrap(),
+            "Bearer realm=\"user-api\", error=\"invalid_token\", error_description=\"Token has expired\""
+        );
+    }
+}
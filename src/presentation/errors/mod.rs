@@ -0,0 +1,4 @@
+pub mod auth_error;
+pub mod problem_details;
+pub mod request_error;
+pub mod user_error;
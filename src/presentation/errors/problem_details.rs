@@ -0,0 +1,59 @@
+use crate::core::errors::user_error::FieldError;
+use crate::presentation::middleware::request_id::current_request_id;
+use actix_web::http::StatusCode;
+use actix_web::HttpResponse;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// An RFC 7807 problem document, extended with a per-field `errors` map and
+/// the id of the request that produced it.
+#[derive(Debug, Serialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub errors: BTreeMap<String, Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl ProblemDetails {
+    pub fn new(status: StatusCode, problem_type: &str, title: &str) -> Self {
+        ProblemDetails {
+            problem_type: problem_type.to_string(),
+            title: title.to_string(),
+            status: status.as_u16(),
+            detail: None,
+            errors: BTreeMap::new(),
+            request_id: current_request_id(),
+        }
+    }
+
+    pub fn with_detail(mut self, detail: &str) -> Self {
+        self.detail = Some(detail.to_string());
+        self
+    }
+
+    pub fn with_field_errors(mut self, errors: &[FieldError]) -> Self {
+        for error in errors {
+            self.errors
+                .entry(error.field.clone())
+                .or_default()
+                .push(error.message.clone());
+        }
+        self
+    }
+
+    pub fn to_response(&self) -> HttpResponse {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        HttpResponse::build(status)
+            .content_type(PROBLEM_JSON)
+            .json(self)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::core::errors::user_error::FieldError;
+use crate::presentation::errors::problem_details::ProblemDetails;
+use actix_web::error::{InternalError, JsonPayloadError, QueryPayloadError};
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpRequest};
+
+/// Serde reports only the first missing member, as "missing field `name`".
+fn missing_field(message: &str) -> Option<FieldError> {
+    let rest = message.split("missing field `").nth(1)?;
+    let field = rest.split('`').next()?;
+    Some(FieldError::new(field, "Field is required"))
+}
+
+fn malformed_request(detail: &str) -> ProblemDetails {
+    let problem = ProblemDetails::new(
+        StatusCode::BAD_REQUEST,
+        "/problems/malformed-request",
+        "Malformed request",
+    )
+    .with_detail(detail);
+    match missing_field(detail) {
+        Some(error) => problem.with_field_errors(&[error]),
+        None => problem,
+    }
+}
+
+fn json_error_handler(error: JsonPayloadError, _request: &HttpRequest) -> actix_web::Error {
+    let problem = match &error {
+        JsonPayloadError::ContentType => ProblemDetails::new(
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            "/problems/unsupported-media-type",
+            "Unsupported media type",
+        )
+        .with_detail("Request body must be JSON"),
+        JsonPayloadError::Overflow { .. } | JsonPayloadError::OverflowKnownLength { .. } => {
+            ProblemDetails::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "/problems/payload-too-large",
+                "Payload too large",
+            )
+        }
+        JsonPayloadError::Deserialize(inner) => malformed_request(&inner.to_string()),
+        error => malformed_request(&error.to_string()),
+    };
+    InternalError::from_response(error, problem.to_response()).into()
+}
+
+fn query_error_handler(error: QueryPayloadError, _request: &HttpRequest) -> actix_web::Error {
+    let detail = match &error {
+        QueryPayloadError::Deserialize(inner) => inner.to_string(),
+        error => error.to_string(),
+    };
+    let problem = malformed_request(&detail);
+    InternalError::from_response(error, problem.to_response()).into()
+}
+
+pub fn json_config() -> web::JsonConfig {
+    web::JsonConfig::default().error_handler(json_error_handler)
+}
+
+pub fn query_config() -> web::QueryConfig {
+    web::QueryConfig::default().error_handler(query_error_handler)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_field_is_reported_per_field() {
+        let problem = malformed_request("missing field `email` at line 1 column 16");
+        assert_eq!(
+            problem.errors.get("email"),
+            Some(&vec!["Field is required".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_other_errors_have_no_field_details() {
+        let problem = malformed_request("expected value at line 1 column 1");
+        assert!(problem.errors.is_empty());
+    }
+}
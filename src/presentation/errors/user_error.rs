@@ -0,0 +1,82 @@
+use crate::core::errors::user_error::UserError;
+use crate::presentation::errors::problem_details::ProblemDetails;
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
+use tracing::error;
+
+impl ResponseError for UserError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            UserError::NotFound => StatusCode::NOT_FOUND,
+            UserError::Conflict(_) => StatusCode::CONFLICT,
+            UserError::Validation(_)
+            | UserError::InvalidVerificationToken
+            | UserError::InvalidResetToken => StatusCode::BAD_REQUEST,
+            UserError::Forbidden(_) => StatusCode::FORBIDDEN,
+            UserError::InvalidCredentials | UserError::InvalidRefreshToken => {
+                StatusCode::UNAUTHORIZED
+            }
+            UserError::SessionNotFound => StatusCode::NOT_FOUND,
+            UserError::Storage(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            UserError::Unavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let status = self.status_code();
+        let problem = match self {
+            UserError::NotFound => {
+                ProblemDetails::new(status, "/problems/not-found", "User not found")
+            }
+            UserError::Conflict(message) => {
+                ProblemDetails::new(status, "/problems/conflict", "Conflict").with_detail(message)
+            }
+            UserError::Validation(errors) => {
+                ProblemDetails::new(status, "/problems/validation", "Validation failed")
+                    .with_detail("One or more fields are invalid")
+                    .with_field_errors(errors)
+            }
+            UserError::Forbidden(reason) => {
+                ProblemDetails::new(status, "/problems/forbidden", "Forbidden").with_detail(reason)
+            }
+            UserError::InvalidCredentials => ProblemDetails::new(
+                status,
+                "/problems/invalid-credentials",
+                "Invalid credentials",
+            )
+            .with_detail("The email or password is incorrect"),
+            UserError::InvalidRefreshToken => ProblemDetails::new(
+                status,
+                "/problems/invalid-refresh-token",
+                "Invalid refresh token",
+            )
+            .with_detail("The refresh token is invalid, expired or revoked"),
+            UserError::SessionNotFound => {
+                ProblemDetails::new(status, "/problems/not-found", "Session not found")
+            }
+            UserError::InvalidVerificationToken => ProblemDetails::new(
+                status,
+                "/problems/invalid-verification-token",
+                "Invalid verification token",
+            )
+            .with_detail("The verification link is invalid, expired or already used"),
+            UserError::InvalidResetToken => ProblemDetails::new(
+                status,
+                "/problems/invalid-reset-token",
+                "Invalid password reset token",
+            )
+            .with_detail("The password reset link is invalid, expired or already used"),
+            UserError::Storage(message) => {
+                error!(error = %message, "Storage error");
+                ProblemDetails::new(status, "/problems/storage", "Storage error")
+                    .with_detail("An unexpected error occurred while accessing storage")
+            }
+            UserError::Unavailable(message) => {
+                error!(error = %message, "User store unavailable");
+                ProblemDetails::new(status, "/problems/unavailable", "Service unavailable")
+                    .with_detail("The user store is temporarily unavailable")
+            }
+        };
+        problem.to_response()
+    }
+}
@@ -0,0 +1,168 @@
+use crate::core::domain::caller::Caller;
+use crate::core::errors::auth_error::AuthError;
+use crate::infrastructure::jwt::JwtVerifier;
+use actix_web::body::MessageBody;
+use actix_web::dev::{Payload, ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderMap, AUTHORIZATION};
+use actix_web::middleware::Next;
+use actix_web::{web, Error, FromRequest, HttpMessage, HttpRequest};
+use std::future::{ready, Ready};
+use tracing::{info, Span};
+
+/// The outcome of checking a request's bearer token, kept in the request
+/// extensions for the `Caller` extractor.
+#[derive(Clone)]
+struct Authentication(Result<Caller, AuthError>);
+
+fn bearer_token(headers: &HeaderMap) -> Result<&str, AuthError> {
+    let value = headers
+        .get(AUTHORIZATION)
+        .ok_or(AuthError::MissingToken)?
+        .to_str()
+        .map_err(|_| AuthError::InvalidToken("Malformed Authorization header".to_string()))?;
+    match value.split_once(' ') {
+        Some((scheme, token)) if scheme.eq_ignore_ascii_case("bearer") && !token.is_empty() => {
+            Ok(token.trim())
+        }
+        _ => Err(AuthError::InvalidToken(
+            "Authorization must use the Bearer scheme".to_string(),
+        )),
+    }
+}
+
+/// Verifies the bearer token, if any, and records the caller. Requests are
+/// never rejected here: handlers opt in by extracting a `Caller`, so public
+/// routes keep working whatever the header holds.
+pub async fn authenticate(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    if let Some(verifier) = request.app_data::<web::Data<JwtVerifier>>() {
+        let outcome = bearer_token(request.headers()).and_then(|token| verifier.verify(token));
+        match &outcome {
+            Ok(caller) => {
+                Span::current().record("subject", caller.subject.as_str());
+            }
+            Err(AuthError::MissingToken) => {}
+            Err(error) => info!(reason = %error, "Rejected bearer token"),
+        }
+        request.extensions_mut().insert(Authentication(outcome));
+    }
+    next.call(request).await
+}
+
+/// Requires an authenticated caller, answering 401 otherwise.
+impl FromRequest for Caller {
+    type Error = AuthError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(request: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(
+            request
+                .extensions()
+                .get::<Authentication>()
+                .map_or(Err(AuthError::MissingToken), |authentication| {
+                    authentication.0.clone()
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::auth_config::AuthConfig;
+    use actix_web::http::header::WWW_AUTHENTICATE;
+    use actix_web::middleware::from_fn;
+    use actix_web::test::{call_service, init_service, read_body, TestRequest};
+    use actix_web::{App, HttpResponse};
+    use jsonwebtoken::{encode, EncodingKey, Header};
+    use serde_json::json;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    const SECRET: &str = "an-hs256-test-secret-of-32-bytes!";
+
+    fn verifier() -> web::Data<JwtVerifier> {
+        web::Data::new(
+            JwtVerifier::from_config(&AuthConfig {
+                jwt_secret: Some(SECRET.to_string()),
+                jwks_file: None,
+                issuer: "user-api".to_string(),
+                audience: "user-api".to_string(),
+                leeway: Duration::ZERO,
+                token_ttl: Duration::from_secs(60),
+                refresh_token_ttl: Duration::from_secs(60),
+                signing_key_file: None,
+                signing_key_id: None,
+                initial_admin_email: None,
+            })
+            .unwrap(),
+        )
+    }
+
+    fn token() -> String {
+        let exp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 60;
+        let claims = json!({"sub": "7", "iss": "user-api", "aud": "user-api", "exp": exp});
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(SECRET.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_bearer_token_requires_the_bearer_scheme() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(bearer_token(&headers), Err(AuthError::MissingToken));
+        headers.insert(AUTHORIZATION, "Basic dXNlcjpwYXNz".parse().unwrap());
+        assert!(matches!(
+            bearer_token(&headers),
+            Err(AuthError::InvalidToken(_))
+        ));
+        headers.insert(AUTHORIZATION, "bearer abc.def.ghi".parse().unwrap());
+        assert_eq!(bearer_token(&headers), Ok("abc.def.ghi"));
+    }
+
+    #[actix_web::test]
+    async fn test_caller_extractor() {
+        let app = init_service(
+            App::new()
+                .app_data(verifier())
+                .wrap(from_fn(authenticate))
+                .route(
+                    "/me",
+                    web::get().to(|caller: Caller| async move {
+                        HttpResponse::Ok().body(caller.subject)
+                    }),
+                )
+                .route("/public", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        let request = TestRequest::get()
+            .uri("/me")
+            .insert_header((AUTHORIZATION, format!("Bearer {}", token())))
+            .to_request();
+        let response = call_service(&app, request).await;
+        assert_eq!(response.status(), 200);
+        assert_eq!(read_body(response).await, "7");
+
+        let response = call_service(&app, TestRequest::get().uri("/me").to_request()).await;
+        assert_eq!(response.status(), 401);
+        assert_eq!(
+            response.headers().get(WWW_AUTHENTICATE).unwrap(),
+            "Bearer realm=\"user-api\""
+        );
+
+        let request = TestRequest::get()
+            .uri("/public")
+            .insert_header((AUTHORIZATION, "Bearer garbage"))
+            .to_request();
+        assert_eq!(call_service(&app, request).await.status(), 200);
+    }
+}
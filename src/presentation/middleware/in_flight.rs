@@ -0,0 +1,17 @@
+use crate::infrastructure::shutdown::Shutdown;
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::middleware::Next;
+use actix_web::{web, Error};
+
+/// Counts the request as in flight, from routing until its response is ready,
+/// so shutdown can wait for it.
+pub async fn track_in_flight(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let _guard = request
+        .app_data::<web::Data<Shutdown>>()
+        .map(|shutdown| shutdown.track());
+    next.call(request).await
+}
@@ -0,0 +1,53 @@
+use crate::infrastructure::metrics::METRICS;
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::middleware::Next;
+use actix_web::Error;
+use std::time::Instant;
+
+/// Only the user handlers are measured; probes and `/metrics` itself would
+/// just add noise.
+fn is_measured(route: &str) -> bool {
+    route == "/user"
+        || route == "/users"
+        || route.starts_with("/user/")
+        || route.starts_with("/auth/")
+        || route == "/verify"
+}
+
+/// Records the count and latency of each request by matched route and status.
+pub async fn record_metrics(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let started = Instant::now();
+    let response = next.call(request).await?;
+    // The pattern is only known once routing has run.
+    let route = response.request().match_pattern();
+    if let Some(route) = route.filter(|route| is_measured(route)) {
+        METRICS.observe_request(
+            response.request().method().as_str(),
+            &route,
+            response.status().as_u16(),
+            started.elapsed(),
+        );
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_api_routes_are_measured() {
+        assert!(is_measured("/user"));
+        assert!(is_measured("/users"));
+        assert!(is_measured("/user/{id}"));
+        assert!(is_measured("/auth/login"));
+        assert!(is_measured("/verify"));
+        assert!(!is_measured("/healthz"));
+        assert!(!is_measured("/metrics"));
+        assert!(!is_measured("/username"));
+    }
+}
@@ -0,0 +1,4 @@
+pub mod authenticate;
+pub mod in_flight;
+pub mod metrics;
+pub mod request_id;
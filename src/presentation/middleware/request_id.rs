@@ -0,0 +1,117 @@
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::middleware::Next;
+use actix_web::Error;
+use std::time::Instant;
+use tracing::{field, info, info_span, Instrument};
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Incoming ids longer than this are replaced rather than logged verbatim.
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// The id of the request being handled on this task, if any.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|id| id.clone()).ok()
+}
+
+/// Keeps a caller-supplied id when it is safe to log and echo back,
+/// otherwise generates a new one.
+fn resolve_request_id(incoming: Option<&HeaderValue>) -> String {
+    incoming
+        .and_then(|value| value.to_str().ok())
+        .filter(|id| {
+            !id.is_empty()
+                && id.len() <= MAX_REQUEST_ID_LENGTH
+                && id.chars().all(|c| c.is_ascii_graphic())
+        })
+        .map(str::to_string)
+        .unwrap_or_else(|| Uuid::new_v4().to_string())
+}
+
+/// Tags the request with an id, runs it inside a `request` span carrying that
+/// id and echoes it in the `X-Request-Id` response header.
+pub async fn request_id(
+    request: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let id = resolve_request_id(request.headers().get(REQUEST_ID_HEADER));
+    let span = info_span!(
+        "request",
+        request_id = %id,
+        method = %request.method(),
+        path = %request.path(),
+        subject = field::Empty
+    );
+    let started = Instant::now();
+
+    let result = REQUEST_ID
+        .scope(id.clone(), next.call(request))
+        .instrument(span.clone())
+        .await;
+
+    let mut response = result?;
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    span.in_scope(|| {
+        info!(
+            status = response.status().as_u16(),
+            latency_ms = started.elapsed().as_millis() as u64,
+            "Request completed"
+        )
+    });
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::middleware::from_fn;
+    use actix_web::test::{call_service, init_service, read_body, TestRequest};
+    use actix_web::{web, App, HttpResponse};
+
+    #[test]
+    fn test_resolve_request_id_keeps_valid_ids() {
+        let value = HeaderValue::from_static("abc-123");
+        assert_eq!(resolve_request_id(Some(&value)), "abc-123");
+    }
+
+    #[test]
+    fn test_resolve_request_id_replaces_invalid_ids() {
+        let long = "a".repeat(MAX_REQUEST_ID_LENGTH + 1);
+        for value in ["", "has space", long.as_str()] {
+            let value = HeaderValue::from_str(value).unwrap();
+            let id = resolve_request_id(Some(&value));
+            assert!(Uuid::parse_str(&id).is_ok(), "{:?} was kept", value);
+        }
+        assert!(Uuid::parse_str(&resolve_request_id(None)).is_ok());
+    }
+
+    #[actix_web::test]
+    async fn test_request_id_is_visible_to_handlers_and_echoed() {
+        let app = init_service(App::new().wrap(from_fn(request_id)).route(
+            "/",
+            web::get().to(|| async { HttpResponse::Ok().body(current_request_id().unwrap()) }),
+        ))
+        .await;
+
+        let request = TestRequest::get()
+            .uri("/")
+            .insert_header((REQUEST_ID_HEADER, "trace-42"))
+            .to_request();
+        let response = call_service(&app, request).await;
+
+        assert_eq!(
+            response.headers().get(REQUEST_ID_HEADER).unwrap(),
+            "trace-42"
+        );
+        assert_eq!(read_body(response).await, "trace-42");
+    }
+}
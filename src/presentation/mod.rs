@@ -1 +1,3 @@
 pub mod controllers;
+pub mod errors;
+pub mod middleware;
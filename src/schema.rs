@@ -1,9 +1,74 @@
-use diesel::table;
+use diesel::{allow_tables_to_appear_in_same_query, joinable, table};
+
+table! {
+    email_verification_tokens (token_hash) {
+        token_hash -> Varchar,
+        user_id -> Int4,
+        email -> Varchar,
+        created_at -> Timestamptz,
+        expires_at -> Timestamptz,
+        used_at -> Nullable<Timestamptz>,
+    }
+}
+
+table! {
+    password_reset_tokens (token_hash) {
+        token_hash -> Varchar,
+        user_id -> Int4,
+        created_at -> Timestamptz,
+        expires_at -> Timestamptz,
+        used_at -> Nullable<Timestamptz>,
+    }
+}
+
+table! {
+    roles (name) {
+        name -> Varchar,
+    }
+}
+
+table! {
+    sessions (id) {
+        id -> Uuid,
+        user_id -> Int4,
+        refresh_token_hash -> Varchar,
+        user_agent -> Nullable<Varchar>,
+        ip_address -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        last_used_at -> Timestamptz,
+        expires_at -> Timestamptz,
+        revoked_at -> Nullable<Timestamptz>,
+    }
+}
+
+table! {
+    user_roles (user_id, role) {
+        user_id -> Int4,
+        role -> Varchar,
+    }
+}
 
 table! {
     users (id) {
         id -> Int4,
         name -> Varchar,
         email -> Varchar,
+        password_hash -> Nullable<Varchar>,
+        email_verified_at -> Nullable<Timestamptz>,
     }
 }
+
+joinable!(email_verification_tokens -> users (user_id));
+joinable!(password_reset_tokens -> users (user_id));
+joinable!(sessions -> users (user_id));
+joinable!(user_roles -> roles (role));
+joinable!(user_roles -> users (user_id));
+
+allow_tables_to_appear_in_same_query!(
+    email_verification_tokens,
+    password_reset_tokens,
+    roles,
+    sessions,
+    user_roles,
+    users
+);
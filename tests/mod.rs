@@ -1 +1,3 @@
 mod presentation;
+mod server;
+mod support;
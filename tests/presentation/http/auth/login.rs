@@ -0,0 +1,110 @@
+use crate::support::{authorized_client, unique_email};
+use reqwest::{Client, Response};
+use serde::Deserialize;
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct Token {
+    access_token: String,
+    token_type: String,
+    expires_in: u64,
+}
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct Problem {
+    status: u16,
+    r#type: String,
+}
+
+async fn login(email: &str, password: &str) -> Response {
+    Client::new()
+        .post("http://localhost:8080/auth/login")
+        .json(&serde_json::json!({"email": email, "password": password}))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[tokio::test]
+async fn test_login_issues_a_token_and_password_can_be_changed() {
+    let email = unique_email("login");
+    let response = authorized_client()
+        .post("http://localhost:8080/user")
+        .json(&serde_json::json!({
+            "name": "Login",
+            "email": email,
+            "password": "first-password"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    let body: serde_json::Value = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+    assert!(body.get("password").is_none());
+    assert!(body.get("password_hash").is_none());
+    let id = body["id"].as_i64().unwrap();
+
+    let response = login(&email.to_uppercase(), "first-password").await;
+    assert_eq!(response.status(), 200);
+    let token: Token = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+    assert_eq!(token.token_type, "Bearer");
+    assert!(token.expires_in > 0);
+
+    let client = Client::new();
+    let response = client
+        .get(format!("http://localhost:8080/user/{}", id))
+        .bearer_auth(&token.access_token)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    let response = client
+        .put(format!("http://localhost:8080/user/{}/password", id))
+        .bearer_auth(&token.access_token)
+        .json(&serde_json::json!({
+            "current_password": "wrong-password",
+            "new_password": "second-password"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 401);
+
+    let response = client
+        .put(format!("http://localhost:8080/user/{}/password", id))
+        .bearer_auth(&token.access_token)
+        .json(&serde_json::json!({
+            "current_password": "first-password",
+            "new_password": "second-password"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 204);
+
+    let response = login(&email, "first-password").await;
+    assert_eq!(response.status(), 401);
+    let response = login(&email, "second-password").await;
+    assert_eq!(response.status(), 200);
+}
+
+#[tokio::test]
+async fn test_login_with_unknown_email_is_rejected() {
+    let response = login("nobody@email.com", "any-password").await;
+
+    assert_eq!(response.status(), 401);
+    let problem: Problem = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+    assert_eq!(problem.status, 401);
+    assert_eq!(problem.r#type, "/problems/invalid-credentials");
+}
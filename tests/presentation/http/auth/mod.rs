@@ -0,0 +1,3 @@
+mod login;
+mod password_reset;
+mod sessions;
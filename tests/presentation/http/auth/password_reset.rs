@@ -0,0 +1,83 @@
+use crate::support::{authorized_client, outbox_token, unique_email};
+use reqwest::{Client, Response};
+
+async fn post(path: &str, body: serde_json::Value) -> Response {
+    Client::new()
+        .post(format!("http://localhost:8080{}", path))
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+async fn login(email: &str, password: &str) -> Response {
+    post(
+        "/auth/login",
+        serde_json::json!({"email": email, "password": password}),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_password_can_be_reset_once_by_emailed_token() {
+    let email = unique_email("reset-link");
+    let response = authorized_client()
+        .post("http://localhost:8080/user")
+        .json(&serde_json::json!({"name": "Reset", "email": email, "password": "old-password"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+    let refresh_token = login(&email, "old-password")
+        .await
+        .json::<serde_json::Value>()
+        .await
+        .expect("Failed to parse response body.")["refresh_token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let response = post("/auth/forgot-password", serde_json::json!({"email": email})).await;
+    assert_eq!(response.status(), 202);
+    let token = outbox_token(&email, "/reset-password?token=");
+
+    let response = post(
+        "/auth/reset-password",
+        serde_json::json!({"token": token, "new_password": "short"}),
+    )
+    .await;
+    assert_eq!(response.status(), 400);
+
+    let response = post(
+        "/auth/reset-password",
+        serde_json::json!({"token": token, "new_password": "new-password"}),
+    )
+    .await;
+    assert_eq!(response.status(), 204);
+
+    assert_eq!(login(&email, "old-password").await.status(), 401);
+    assert_eq!(login(&email, "new-password").await.status(), 200);
+    let response = post(
+        "/auth/refresh",
+        serde_json::json!({"refresh_token": refresh_token}),
+    )
+    .await;
+    assert_eq!(response.status(), 401);
+
+    let response = post(
+        "/auth/reset-password",
+        serde_json::json!({"token": token, "new_password": "another-password"}),
+    )
+    .await;
+    assert_eq!(response.status(), 400);
+}
+
+#[tokio::test]
+async fn test_forgot_password_does_not_reveal_unknown_emails() {
+    let response = post(
+        "/auth/forgot-password",
+        serde_json::json!({"email": unique_email("nobody-here")}),
+    )
+    .await;
+    assert_eq!(response.status(), 202);
+}
@@ -0,0 +1,142 @@
+use crate::support::{authorized_client, unique_email};
+use reqwest::{Client, Response};
+use serde::Deserialize;
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct Token {
+    access_token: String,
+    refresh_token: String,
+}
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct Session {
+    id: String,
+    user_agent: Option<String>,
+}
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct Sessions {
+    data: Vec<Session>,
+}
+
+async fn create_user(email: &str) -> i64 {
+    authorized_client()
+        .post("http://localhost:8080/user")
+        .json(&serde_json::json!({"name": "Sessions", "email": email, "password": "session-password"}))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json::<serde_json::Value>()
+        .await
+        .expect("Failed to parse response body.")["id"]
+        .as_i64()
+        .unwrap()
+}
+
+async fn login(email: &str) -> Token {
+    Client::new()
+        .post("http://localhost:8080/auth/login")
+        .header("User-Agent", "sessions-test/1.0")
+        .json(&serde_json::json!({"email": email, "password": "session-password"}))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json()
+        .await
+        .expect("Failed to parse response body.")
+}
+
+async fn post(path: &str, refresh_token: &str) -> Response {
+    Client::new()
+        .post(format!("http://localhost:8080{}", path))
+        .json(&serde_json::json!({"refresh_token": refresh_token}))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+}
+
+#[tokio::test]
+async fn test_refresh_rotates_and_detects_reuse() {
+    let email = unique_email("sessions-rotate");
+    create_user(&email).await;
+    let first = login(&email).await;
+
+    let response = post("/auth/refresh", &first.refresh_token).await;
+    assert_eq!(response.status(), 200);
+    let second: Token = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+    assert_ne!(second.refresh_token, first.refresh_token);
+
+    let response = post("/auth/refresh", &first.refresh_token).await;
+    assert_eq!(response.status(), 401);
+    // The reuse revoked the session, so the newest token is dead as well.
+    let response = post("/auth/refresh", &second.refresh_token).await;
+    assert_eq!(response.status(), 401);
+}
+
+#[tokio::test]
+async fn test_sessions_can_be_listed_logged_out_and_revoked() {
+    let email = unique_email("sessions-list");
+    let id = create_user(&email).await;
+    let first = login(&email).await;
+    let second = login(&email).await;
+    let client = Client::new();
+
+    let sessions: Sessions = client
+        .get(format!("http://localhost:8080/user/{}/sessions", id))
+        .bearer_auth(&first.access_token)
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+    assert_eq!(sessions.data.len(), 2);
+    assert_eq!(
+        sessions.data[0].user_agent.as_deref(),
+        Some("sessions-test/1.0")
+    );
+
+    let response = post("/auth/logout", &first.refresh_token).await;
+    assert_eq!(response.status(), 204);
+    let response = post("/auth/refresh", &first.refresh_token).await;
+    assert_eq!(response.status(), 401);
+
+    // Newest first, so this is the second login.
+    let path = format!(
+        "http://localhost:8080/user/{}/sessions/{}",
+        id, sessions.data[0].id
+    );
+    let response = client
+        .delete(&path)
+        .bearer_auth(&second.access_token)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 204);
+    let response = post("/auth/refresh", &second.refresh_token).await;
+    assert_eq!(response.status(), 401);
+    let response = client
+        .delete(&path)
+        .bearer_auth(&second.access_token)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 404);
+
+    let third = login(&email).await;
+    let response = client
+        .delete(format!("http://localhost:8080/user/{}/sessions", id))
+        .bearer_auth(&second.access_token)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 204);
+    let response = post("/auth/refresh", &third.refresh_token).await;
+    assert_eq!(response.status(), 401);
+}
@@ -0,0 +1,46 @@
+use reqwest::Client;
+use serde::Deserialize;
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct Response {
+    status: String,
+}
+
+#[tokio::test]
+async fn test_healthz() {
+    let client = Client::new();
+
+    let response = client
+        .get("http://localhost:8080/healthz")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 200);
+
+    let response_body: Response = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+
+    assert_eq!(response_body.status, "ok");
+}
+
+#[tokio::test]
+async fn test_request_id_is_generated_when_missing() {
+    let client = Client::new();
+
+    let response = client
+        .get("http://localhost:8080/healthz")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    let request_id = response
+        .headers()
+        .get("x-request-id")
+        .expect("Missing X-Request-Id header.");
+
+    assert!(!request_id.is_empty());
+}
@@ -0,0 +1,40 @@
+use reqwest::Client;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct Check {
+    status: String,
+}
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct Response {
+    status: String,
+    checks: HashMap<String, Check>,
+}
+
+#[tokio::test]
+async fn test_readyz() {
+    let client = Client::new();
+
+    let response = client
+        .get("http://localhost:8080/readyz")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 200);
+
+    let response_body: Response = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+
+    assert_eq!(response_body.status, "ready");
+    assert!(response_body
+        .checks
+        .values()
+        .all(|check| check.status == "up"));
+}
@@ -0,0 +1,33 @@
+use crate::support::authorized_client;
+
+#[tokio::test]
+async fn test_get_metrics() {
+    let client = authorized_client();
+
+    client
+        .get("http://localhost:8080/user/999999999")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    let response = client
+        .get("http://localhost:8080/metrics")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 200);
+    assert!(response.headers()["content-type"]
+        .to_str()
+        .unwrap()
+        .starts_with("text/plain"));
+
+    let body = response
+        .text()
+        .await
+        .expect("Failed to read response body.");
+
+    assert!(body.contains(r#"http_requests_total{method="GET",route="/user/{id}",status="404"}"#));
+    assert!(body.contains("http_request_duration_seconds_bucket"));
+    assert!(!body.contains(r#"route="/metrics""#));
+}
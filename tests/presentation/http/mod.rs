@@ -1 +1,4 @@
+mod auth;
+mod health;
+mod metrics;
 mod users;
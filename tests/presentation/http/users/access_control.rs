@@ -0,0 +1,86 @@
+use crate::support::{authorized_client, client_for, unique_email};
+use serde::Deserialize;
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct User {
+    id: i32,
+}
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct Problem {
+    status: u16,
+    detail: String,
+}
+
+async fn create_user(name: &str, email: &str) -> i32 {
+    authorized_client()
+        .post("http://localhost:8080/user")
+        .json(&serde_json::json!({"name": name, "email": email, "password": "user-password"}))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json::<User>()
+        .await
+        .expect("Failed to parse response body.")
+        .id
+}
+
+#[tokio::test]
+async fn test_users_can_read_and_edit_only_their_own_record() {
+    let own_id = create_user("Owner", &unique_email("rbac-owner")).await;
+    let other_id = create_user("Other", &unique_email("rbac-other")).await;
+    let client = client_for(&own_id.to_string(), &["user"]);
+
+    let response = client
+        .get(format!("http://localhost:8080/user/{}", own_id))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    let response = client
+        .patch(format!("http://localhost:8080/user/{}", own_id))
+        .json(&serde_json::json!({"name": "Owner Renamed"}))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+
+    let response = client
+        .get(format!("http://localhost:8080/user/{}", other_id))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 403);
+    let problem: Problem = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+    assert_eq!(problem.status, 403);
+    assert_eq!(problem.detail, "Users can only read their own record");
+
+    let response = client
+        .delete(format!("http://localhost:8080/user/{}", own_id))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 403);
+}
+
+#[tokio::test]
+async fn test_only_admins_can_list_users() {
+    let response = client_for("12345", &["user"])
+        .get("http://localhost:8080/users")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 403);
+    let problem: Problem = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+    assert_eq!(problem.detail, "Only admins can list users");
+}
@@ -1,10 +1,11 @@
-use reqwest::Client;
+use crate::support::{authorized_client, unique_email};
 use serde::{Deserialize, Serialize};
 #[cfg(test)]
 #[derive(Debug, Serialize, Deserialize)]
 struct Request {
     name: String,
     email: String,
+    password: String,
 }
 
 #[cfg(test)]
@@ -17,11 +18,13 @@ struct Response {
 
 #[tokio::test]
 async fn test_create_user() {
-    let client = Client::new();
+    let client = authorized_client();
+    let email = unique_email("john");
 
     let user = Request {
         name: "John Doe".to_string(),
-        email: "john@email.com".to_string(),
+        email: email.clone(),
+        password: "user-password".to_string(),
     };
 
     let response = client
@@ -40,5 +43,112 @@ async fn test_create_user() {
         .expect("Failed to parse response body.");
 
     assert_eq!(response_body.name, "John Doe");
-    assert_eq!(response_body.email, "john@email.com");
+    assert_eq!(response_body.email, email);
+}
+
+#[tokio::test]
+async fn test_create_user_with_taken_email_is_conflict() {
+    let client = authorized_client();
+    let email = unique_email("taken");
+
+    let response = client
+        .post("http://localhost:8080/user")
+        .header("Content-Type", "application/json")
+        .json(&Request {
+            name: "Taken Email".to_string(),
+            email: email.clone(),
+            password: "user-password".to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+
+    let response = client
+        .post("http://localhost:8080/user")
+        .header("Content-Type", "application/json")
+        .json(&Request {
+            name: "Taken Email Again".to_string(),
+            email: email.to_uppercase(),
+            password: "user-password".to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 409);
+}
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct Problem {
+    #[serde(rename = "type")]
+    problem_type: String,
+    status: u16,
+    #[serde(default)]
+    errors: std::collections::HashMap<String, Vec<String>>,
+}
+
+#[tokio::test]
+async fn test_create_user_with_invalid_fields_reports_each_field() {
+    let client = authorized_client();
+
+    let response = client
+        .post("http://localhost:8080/user")
+        .header("Content-Type", "application/json")
+        .json(&Request {
+            name: "a".repeat(101),
+            email: "not-an-email".to_string(),
+            password: "short".to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 400);
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/problem+json"
+    );
+
+    let problem: Problem = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+
+    assert_eq!(problem.problem_type, "/problems/validation");
+    assert_eq!(problem.status, 400);
+    assert!(problem.errors.contains_key("name"));
+    assert!(problem.errors.contains_key("email"));
+    assert!(problem.errors.contains_key("password"));
+}
+
+#[tokio::test]
+async fn test_create_user_with_missing_field_is_problem() {
+    let client = authorized_client();
+
+    let response = client
+        .post("http://localhost:8080/user")
+        .header("Content-Type", "application/json")
+        .body(r#"{"name":"John Doe"}"#)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 400);
+    assert_eq!(
+        response.headers()["content-type"],
+        "application/problem+json"
+    );
+
+    let problem: Problem = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+
+    assert_eq!(problem.problem_type, "/problems/malformed-request");
+    assert_eq!(
+        problem.errors.get("email"),
+        Some(&vec!["Field is required".to_string()])
+    );
 }
@@ -1,5 +1,7 @@
+use crate::support::authorized_client;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+
 #[cfg(test)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct User {
@@ -8,9 +10,15 @@ struct User {
     email: String,
 }
 
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct UserList {
+    data: Vec<User>,
+}
+
 #[tokio::test]
 async fn test_delete_user() {
-    let client = Client::new();
+    let client = authorized_client();
 
     let users = client
         .get("http://localhost:8080/users")
@@ -19,9 +27,10 @@ async fn test_delete_user() {
         .expect("Failed to execute request.");
 
     let users = users
-        .json::<Vec<User>>()
+        .json::<UserList>()
         .await
-        .expect("Failed to parse response body.");
+        .expect("Failed to parse response body.")
+        .data;
 
     let user = users[0].clone();
 
@@ -42,3 +51,34 @@ async fn test_delete_user() {
     assert_eq!(response_user.name, user.name);
     assert_eq!(response_user.email, user.email);
 }
+
+#[tokio::test]
+async fn test_delete_user_requires_a_token() {
+    let response = Client::new()
+        .delete("http://localhost:8080/user/1")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 401);
+    assert_eq!(
+        response.headers()["www-authenticate"],
+        "Bearer realm=\"user-api\""
+    );
+}
+
+#[tokio::test]
+async fn test_delete_user_rejects_an_invalid_token() {
+    let response = Client::new()
+        .delete("http://localhost:8080/user/1")
+        .bearer_auth("not.a.token")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 401);
+    assert!(response.headers()["www-authenticate"]
+        .to_str()
+        .unwrap()
+        .contains("error=\"invalid_token\""));
+}
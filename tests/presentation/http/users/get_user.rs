@@ -1,4 +1,4 @@
-use reqwest::Client;
+use crate::support::authorized_client;
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -9,9 +9,15 @@ struct User {
     email: String,
 }
 
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct UserList {
+    data: Vec<User>,
+}
+
 #[tokio::test]
 async fn test_get_user() {
-    let client = Client::new();
+    let client = authorized_client();
 
     let users = client
         .get("http://localhost:8080/users")
@@ -20,9 +26,10 @@ async fn test_get_user() {
         .expect("Failed to execute request.");
 
     let users = users
-        .json::<Vec<User>>()
+        .json::<UserList>()
         .await
-        .expect("Failed to parse response body.");
+        .expect("Failed to parse response body.")
+        .data;
 
     let user = users[0].clone();
 
@@ -43,3 +50,36 @@ async fn test_get_user() {
     assert_eq!(response_user.name, user.name);
     assert_eq!(response_user.email, user.email);
 }
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct Problem {
+    status: u16,
+    request_id: Option<String>,
+}
+
+#[tokio::test]
+async fn test_get_missing_user_echoes_request_id() {
+    let client = authorized_client();
+
+    let response = client
+        .get("http://localhost:8080/user/999999999")
+        .header("X-Request-Id", "get-user-test-42")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 404);
+    assert_eq!(
+        response.headers().get("x-request-id").unwrap(),
+        "get-user-test-42"
+    );
+
+    let problem: Problem = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+
+    assert_eq!(problem.status, 404);
+    assert_eq!(problem.request_id.as_deref(), Some("get-user-test-42"));
+}
@@ -1,4 +1,4 @@
-use reqwest::Client;
+use crate::support::authorized_client;
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -9,9 +9,17 @@ struct User {
     email: String,
 }
 
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct UserList {
+    data: Vec<User>,
+    total: i64,
+    next_cursor: Option<i32>,
+}
+
 #[tokio::test]
 async fn test_get_users() {
-    let client = Client::new();
+    let client = authorized_client();
 
     let users = client
         .get("http://localhost:8080/users")
@@ -21,7 +29,54 @@ async fn test_get_users() {
 
     assert_eq!(users.status(), 200);
 
-    let users: Vec<User> = users.json().await.expect("Failed to parse response body.");
+    let users: UserList = users.json().await.expect("Failed to parse response body.");
+
+    assert!(!users.data.is_empty());
+    assert!(users.total >= users.data.len() as i64);
+}
+
+#[tokio::test]
+async fn test_get_users_with_cursor() {
+    let client = authorized_client();
+
+    let first_page: UserList = client
+        .get("http://localhost:8080/users?limit=1")
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+
+    assert_eq!(first_page.data.len(), 1);
+
+    if let Some(cursor) = first_page.next_cursor {
+        let second_page: UserList = client
+            .get(format!(
+                "http://localhost:8080/users?limit=1&cursor={}",
+                cursor
+            ))
+            .send()
+            .await
+            .expect("Failed to execute request.")
+            .json()
+            .await
+            .expect("Failed to parse response body.");
+
+        assert_eq!(second_page.data.len(), 1);
+        assert!(second_page.data[0].id > first_page.data[0].id);
+    }
+}
+
+#[tokio::test]
+async fn test_get_users_rejects_unknown_sort() {
+    let client = authorized_client();
+
+    let response = client
+        .get("http://localhost:8080/users?sort=password")
+        .send()
+        .await
+        .expect("Failed to execute request.");
 
-    assert!(!users.is_empty());
+    assert_eq!(response.status(), 400);
 }
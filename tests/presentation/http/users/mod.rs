@@ -1,5 +1,9 @@
+mod access_control;
 mod create_user;
 mod delete_user;
 mod get_user;
 mod get_users;
+mod patch_user;
+mod search_users;
 mod update_user;
+mod verify_email;
@@ -0,0 +1,83 @@
+use crate::support::authorized_client;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct User {
+    id: i32,
+    name: String,
+    email: String,
+}
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct UserList {
+    data: Vec<User>,
+}
+
+#[tokio::test]
+async fn test_patch_user() {
+    let client = authorized_client();
+
+    let users = client
+        .get("http://localhost:8080/users")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    let users = users
+        .json::<UserList>()
+        .await
+        .expect("Failed to parse response body.")
+        .data;
+
+    let user = users[0].clone();
+
+    let response = client
+        .patch(format!("http://localhost:8080/user/{}", user.id))
+        .header("Content-Type", "application/merge-patch+json")
+        .body(r#"{"name": "Patched Name"}"#)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 200);
+
+    let response_user: User = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+
+    assert_eq!(response_user.id, user.id);
+    assert_eq!(response_user.name, "Patched Name");
+    assert_eq!(response_user.email, user.email);
+}
+
+#[tokio::test]
+async fn test_patch_user_rejects_null_fields() {
+    let client = authorized_client();
+
+    let users = client
+        .get("http://localhost:8080/users")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    let users = users
+        .json::<UserList>()
+        .await
+        .expect("Failed to parse response body.")
+        .data;
+
+    let user = users[0].clone();
+
+    let response = client
+        .patch(format!("http://localhost:8080/user/{}", user.id))
+        .header("Content-Type", "application/merge-patch+json")
+        .body(r#"{"email": null}"#)
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 400);
+}
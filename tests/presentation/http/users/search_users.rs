@@ -0,0 +1,93 @@
+use crate::support::{authorized_client, unique_email};
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[derive(Debug, Serialize, Deserialize)]
+struct Request {
+    name: String,
+    email: String,
+    password: String,
+}
+
+#[cfg(test)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct User {
+    id: i32,
+    name: String,
+    email: String,
+}
+
+#[cfg(test)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct UserMatch {
+    id: i32,
+    name: String,
+    email: String,
+    score: f32,
+}
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct SearchResults {
+    data: Vec<UserMatch>,
+}
+
+#[tokio::test]
+async fn test_search_users() {
+    let client = authorized_client();
+
+    let response = client
+        .post("http://localhost:8080/user")
+        .header("Content-Type", "application/json")
+        .json(&Request {
+            name: "Searchable Person".to_string(),
+            email: unique_email("searchable.person"),
+            password: "user-password".to_string(),
+        })
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    let created_user: User = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+
+    let response = client
+        .get("http://localhost:8080/users/search?q=searchable")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 200);
+
+    let results: SearchResults = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+
+    let found = results
+        .data
+        .iter()
+        .find(|user| user.id == created_user.id)
+        .expect("Created user missing from search results.");
+
+    assert!(found.score > 0.0);
+    assert!(results
+        .data
+        .windows(2)
+        .all(|pair| pair[0].score >= pair[1].score));
+}
+
+#[tokio::test]
+async fn test_search_users_requires_query() {
+    let client = authorized_client();
+
+    let response = client
+        .get("http://localhost:8080/users/search")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+
+    assert_eq!(response.status(), 400);
+}
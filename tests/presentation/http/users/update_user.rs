@@ -1,4 +1,4 @@
-use reqwest::Client;
+use crate::support::{authorized_client, unique_email};
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -9,9 +9,16 @@ struct User {
     email: String,
 }
 
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct UserList {
+    data: Vec<User>,
+}
+
 #[tokio::test]
 async fn test_update_user() {
-    let client = Client::new();
+    let client = authorized_client();
+    let email = unique_email("jane");
 
     let users = client
         .get("http://localhost:8080/users")
@@ -20,9 +27,10 @@ async fn test_update_user() {
         .expect("Failed to execute request.");
 
     let users = users
-        .json::<Vec<User>>()
+        .json::<UserList>()
         .await
-        .expect("Failed to parse response body.");
+        .expect("Failed to parse response body.")
+        .data;
 
     let user = users[0].clone();
 
@@ -42,26 +50,39 @@ async fn test_update_user() {
     assert_eq!(response_user.id, user.id);
     assert_eq!(response_user.name, user.name);
     assert_eq!(response_user.email, user.email);
-    
-    let response = client.put(format!("http://localhost:8080/user/{}", user.id))
+
+    let response = client
+        .put(format!("http://localhost:8080/user/{}", user.id))
         .header("Content-Type", "application/json")
         .json(&User {
             id: user.id,
             name: "Jane Doe".to_string(),
-            email: "jane@email.com".to_string(),
+            email: email.clone(),
         })
         .send()
         .await
         .expect("Failed to execute request.");
-    
+
     assert_eq!(response.status(), 200);
-    
+
     let response_user: User = response
         .json()
         .await
         .expect("Failed to parse response body.");
-    
+
     assert_eq!(response_user.id, user.id);
     assert_eq!(response_user.name, "Jane Doe");
-    assert_eq!(response_user.email, "jane@email.com");
+    assert_eq!(response_user.email, email);
+
+    let stored_user: User = client
+        .get(format!("http://localhost:8080/user/{}", user.id))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+
+    assert_eq!(stored_user.name, "Jane Doe");
+    assert_eq!(stored_user.email, email);
 }
@@ -0,0 +1,101 @@
+use crate::support::{authorized_client, outbox_token, unique_email};
+use reqwest::Client;
+use serde::Deserialize;
+
+#[cfg(test)]
+#[derive(Debug, Deserialize)]
+struct Verified {
+    id: i64,
+    email: String,
+    email_verified_at: Option<String>,
+}
+
+#[tokio::test]
+async fn test_email_can_be_verified_once_by_link() {
+    let email = unique_email("verify-link");
+    let id = authorized_client()
+        .post("http://localhost:8080/user")
+        .json(&serde_json::json!({"name": "Verify", "email": email, "password": "user-password"}))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json::<serde_json::Value>()
+        .await
+        .expect("Failed to parse response body.")["id"]
+        .as_i64()
+        .unwrap();
+
+    let response = authorized_client()
+        .post(format!("http://localhost:8080/user/{}/verify-email", id))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 202);
+
+    let token = outbox_token(&email, "/verify?token=");
+    let response = Client::new()
+        .get("http://localhost:8080/verify")
+        .query(&[("token", &token)])
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 200);
+    let verified: Verified = response
+        .json()
+        .await
+        .expect("Failed to parse response body.");
+    assert_eq!((verified.id, verified.email.as_str()), (id, email.as_str()));
+    assert!(verified.email_verified_at.is_some());
+
+    let response = Client::new()
+        .get("http://localhost:8080/verify")
+        .query(&[("token", &token)])
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 400);
+
+    let user = authorized_client()
+        .get(format!("http://localhost:8080/user/{}", id))
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json::<serde_json::Value>()
+        .await
+        .expect("Failed to parse response body.");
+    assert!(user["email_verified_at"].is_string());
+
+    let results = authorized_client()
+        .get("http://localhost:8080/users/search")
+        .query(&[("q", &email)])
+        .send()
+        .await
+        .expect("Failed to execute request.")
+        .json::<serde_json::Value>()
+        .await
+        .expect("Failed to parse response body.");
+    let found = results["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|user| user["id"] == id)
+        .expect("Verified user missing from search results.");
+    assert_eq!(found["email_verified_at"], user["email_verified_at"]);
+
+    let response = authorized_client()
+        .post(format!("http://localhost:8080/user/{}/verify-email", id))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 409);
+}
+
+#[tokio::test]
+async fn test_unknown_verification_token_is_rejected() {
+    let response = Client::new()
+        .get("http://localhost:8080/verify?token=not-a-real-token")
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 400);
+}
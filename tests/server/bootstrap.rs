@@ -0,0 +1,51 @@
+use super::Server;
+use crate::support::{outbox_token, unique_email};
+use reqwest::Client;
+
+#[tokio::test]
+async fn test_initial_admin_can_create_users() {
+    let email = unique_email("initial-admin");
+    let server = Server::start(18081, &[("AUTH_INITIAL_ADMIN_EMAIL", &email)]);
+    server.wait_until_up().await;
+    let client = Client::new();
+    let post =
+        |path: &str, body: serde_json::Value| client.post(server.url(path)).json(&body).send();
+
+    let response = post("/auth/forgot-password", serde_json::json!({"email": email}))
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 202);
+    let token = outbox_token(&email, "/reset-password?token=");
+    let response = post(
+        "/auth/reset-password",
+        serde_json::json!({"token": token, "new_password": "admin-password"}),
+    )
+    .await
+    .expect("Failed to execute request.");
+    assert_eq!(response.status(), 204);
+
+    let access_token = post(
+        "/auth/login",
+        serde_json::json!({"email": email, "password": "admin-password"}),
+    )
+    .await
+    .expect("Failed to execute request.")
+    .json::<serde_json::Value>()
+    .await
+    .expect("Failed to parse response body.")["access_token"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    let response = client
+        .post(server.url("/user"))
+        .bearer_auth(&access_token)
+        .json(&serde_json::json!({
+            "name": "Created By Admin",
+            "email": unique_email("created-by-admin"),
+            "password": "user-password"
+        }))
+        .send()
+        .await
+        .expect("Failed to execute request.");
+    assert_eq!(response.status(), 201);
+}
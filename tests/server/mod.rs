@@ -0,0 +1,64 @@
+mod bootstrap;
+mod shutdown;
+
+use std::process::{Child, Command, Stdio};
+use std::time::Duration;
+
+/// The binary under test, run on its own port with the memory backend.
+#[cfg(test)]
+struct Server {
+    child: Child,
+    port: u16,
+}
+
+#[cfg(test)]
+impl Server {
+    fn start(port: u16, env: &[(&str, &str)]) -> Self {
+        let child = Command::new(env!("CARGO_BIN_EXE_user-api"))
+            .env("DB_TYPE", "memory")
+            .env("PORT", port.to_string())
+            .envs(env.iter().copied())
+            .stdout(Stdio::null())
+            .spawn()
+            .expect("Failed to start server.");
+        Server { child, port }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("http://localhost:{}{}", self.port, path)
+    }
+
+    fn signal(&self, signal: &str) {
+        let status = Command::new("kill")
+            .arg(format!("-{}", signal))
+            .arg(self.child.id().to_string())
+            .status()
+            .expect("Failed to send signal.");
+        assert!(status.success());
+    }
+
+    async fn status_of(&self, path: &str) -> Option<u16> {
+        reqwest::get(self.url(path))
+            .await
+            .ok()
+            .map(|response| response.status().as_u16())
+    }
+
+    async fn wait_until_up(&self) {
+        for _ in 0..50 {
+            if self.status_of("/healthz").await == Some(200) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("Server did not start.");
+    }
+}
+
+#[cfg(test)]
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
@@ -0,0 +1,57 @@
+use super::Server;
+use crate::support::token;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+#[tokio::test]
+async fn test_sigterm_drains_in_flight_request() {
+    let mut server = Server::start(
+        18080,
+        &[("SHUTDOWN_DELAY", "1"), ("SHUTDOWN_TIMEOUT", "10")],
+    );
+    server.wait_until_up().await;
+    assert_eq!(server.status_of("/readyz").await, Some(200));
+
+    let body = r#"{"name":"Drained","email":"drained@email.com","password":"drained-password"}"#;
+    let (head, tail) = body.split_at(10);
+    let mut connection = TcpStream::connect(("localhost", server.port))
+        .await
+        .expect("Failed to connect.");
+    connection
+        .write_all(
+            format!(
+                "POST /user HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\n\
+                 Authorization: Bearer {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                token("shutdown-test", &["admin"]),
+                body.len(),
+                head
+            )
+            .as_bytes(),
+        )
+        .await
+        .expect("Failed to write request head.");
+
+    server.signal("TERM");
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(server.status_of("/readyz").await, Some(503));
+
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+
+    connection
+        .write_all(tail.as_bytes())
+        .await
+        .expect("Failed to write request body.");
+    let mut response = String::new();
+    connection
+        .read_to_string(&mut response)
+        .await
+        .expect("Failed to read response.");
+    assert!(response.starts_with("HTTP/1.1 201"), "{}", response);
+
+    let status = tokio::task::spawn_blocking(move || server.child.wait())
+        .await
+        .unwrap()
+        .expect("Failed to wait for server.");
+    assert!(status.success());
+}
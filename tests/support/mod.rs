@@ -0,0 +1,81 @@
+use jsonwebtoken::{encode, EncodingKey, Header};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use reqwest::Client;
+use serde_json::json;
+use std::env;
+use std::fs;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+/// An HS256 token accepted by a server started with the same `.env`.
+pub fn token(subject: &str, roles: &[&str]) -> String {
+    dotenv::dotenv().ok();
+    let secret = env::var("AUTH_JWT_SECRET").expect("AUTH_JWT_SECRET must be set.");
+    let issuer = env::var("AUTH_ISSUER").unwrap_or("user-api".to_string());
+    let audience = env::var("AUTH_AUDIENCE").unwrap_or("user-api".to_string());
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let claims = json!({
+        "sub": subject,
+        "iss": issuer,
+        "aud": audience,
+        "exp": now + 300,
+        "roles": roles,
+    });
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+    .expect("Failed to sign token.")
+}
+
+/// An address no earlier run has taken, so the suite can run again against
+/// the same server without tripping the unique email constraint.
+pub fn unique_email(prefix: &str) -> String {
+    format!("{}-{}@email.com", prefix, Uuid::new_v4().simple())
+}
+
+/// A client acting as an admin.
+pub fn authorized_client() -> Client {
+    client_for("integration-tests", &["admin"])
+}
+
+/// A client that sends a bearer token for `subject` with every request.
+pub fn client_for(subject: &str, roles: &[&str]) -> Client {
+    let mut headers = HeaderMap::new();
+    let value = HeaderValue::from_str(&format!("Bearer {}", token(subject, roles))).unwrap();
+    headers.insert(AUTHORIZATION, value);
+    Client::builder()
+        .default_headers(headers)
+        .build()
+        .expect("Failed to build client.")
+}
+
+/// The token in the newest link containing `marker` mailed to `to`, read from
+/// the file outbox a server started with the same `.env` writes to. Some mail
+/// is sent after the response, so this waits a few seconds for it to arrive.
+pub fn outbox_token(to: &str, marker: &str) -> String {
+    dotenv::dotenv().ok();
+    let path = env::var("MAIL_OUTBOX_FILE").expect("MAIL_OUTBOX_FILE must be set.");
+    for _ in 0..50 {
+        let contents = fs::read_to_string(&path).unwrap_or_default();
+        let token = contents
+            .lines()
+            .rev()
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .filter(|message| message["to"] == to)
+            .find_map(|message| {
+                let (_, link) = message["body"].as_str()?.split_once(marker)?;
+                link.split_whitespace().next().map(str::to_string)
+            });
+        if let Some(token) = token {
+            return token;
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+    panic!("No matching message in the outbox.");
+}